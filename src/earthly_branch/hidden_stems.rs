@@ -0,0 +1,209 @@
+use super::EarthlyBranch;
+use crate::HeavenlyStems;
+
+/// 從子到亥共十二地支的藏干及其慣用的力量百分比，依序為本氣、中氣、餘氣。
+const HIDDEN_STEMS: [HiddenStems; 12] = [
+    // 子：癸
+    HiddenStems::new(&[(HeavenlyStems::Tenth, 100)]),
+    // 丑：己、癸、辛
+    HiddenStems::new(&[
+        (HeavenlyStems::Sixth, 60),
+        (HeavenlyStems::Tenth, 30),
+        (HeavenlyStems::Eighth, 10),
+    ]),
+    // 寅：甲、丙、戊
+    HiddenStems::new(&[
+        (HeavenlyStems::First, 60),
+        (HeavenlyStems::Third, 30),
+        (HeavenlyStems::Fifth, 10),
+    ]),
+    // 卯：乙
+    HiddenStems::new(&[(HeavenlyStems::Second, 100)]),
+    // 辰：戊、乙、癸
+    HiddenStems::new(&[
+        (HeavenlyStems::Fifth, 60),
+        (HeavenlyStems::Second, 30),
+        (HeavenlyStems::Tenth, 10),
+    ]),
+    // 巳：丙、庚、戊
+    HiddenStems::new(&[
+        (HeavenlyStems::Third, 60),
+        (HeavenlyStems::Seventh, 30),
+        (HeavenlyStems::Fifth, 10),
+    ]),
+    // 午：丁、己
+    HiddenStems::new(&[(HeavenlyStems::Fourth, 70), (HeavenlyStems::Sixth, 30)]),
+    // 未：己、丁、乙
+    HiddenStems::new(&[
+        (HeavenlyStems::Sixth, 60),
+        (HeavenlyStems::Fourth, 30),
+        (HeavenlyStems::Second, 10),
+    ]),
+    // 申：庚、壬、戊
+    HiddenStems::new(&[
+        (HeavenlyStems::Seventh, 60),
+        (HeavenlyStems::Ninth, 30),
+        (HeavenlyStems::Fifth, 10),
+    ]),
+    // 酉：辛
+    HiddenStems::new(&[(HeavenlyStems::Eighth, 100)]),
+    // 戌：戊、辛、丁
+    HiddenStems::new(&[
+        (HeavenlyStems::Fifth, 60),
+        (HeavenlyStems::Eighth, 30),
+        (HeavenlyStems::Fourth, 10),
+    ]),
+    // 亥：壬、甲
+    HiddenStems::new(&[(HeavenlyStems::Ninth, 70), (HeavenlyStems::First, 30)]),
+];
+
+/// 地支藏干，依序為本氣、中氣、餘氣。子、卯、酉只有本氣，午、亥沒有餘氣。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HiddenStems {
+    stems:   [HeavenlyStems; 3],
+    weights: [u8; 3],
+    len:     u8,
+}
+
+impl HiddenStems {
+    /// 利用依序為本氣、中氣、餘氣的藏干及其力量百分比來產生 `HiddenStems` 實體，藏干的數量即為 `hidden_stems` 的長度。
+    const fn new(hidden_stems: &[(HeavenlyStems, u8)]) -> Self {
+        let (main_qi, _) = hidden_stems[0];
+
+        let mut stems = [main_qi; 3];
+        let mut weights = [0; 3];
+
+        let mut i = 0;
+
+        while i < hidden_stems.len() {
+            (stems[i], weights[i]) = hidden_stems[i];
+
+            i += 1;
+        }
+
+        Self {
+            stems,
+            weights,
+            len: hidden_stems.len() as u8,
+        }
+    }
+
+    /// 取得本氣。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{EarthlyBranch, HeavenlyStems};
+    ///
+    /// assert_eq!(
+    ///     HeavenlyStems::Fifth,
+    ///     EarthlyBranch::Fifth.hidden_stems().main_qi()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn main_qi(self) -> HeavenlyStems {
+        self.stems[0]
+    }
+
+    /// 取得中氣。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{EarthlyBranch, HeavenlyStems};
+    ///
+    /// assert_eq!(
+    ///     Some(HeavenlyStems::Second),
+    ///     EarthlyBranch::Fifth.hidden_stems().middle_qi()
+    /// );
+    /// assert_eq!(None, EarthlyBranch::First.hidden_stems().middle_qi());
+    /// ```
+    #[inline]
+    pub const fn middle_qi(self) -> Option<HeavenlyStems> {
+        if self.len > 1 { Some(self.stems[1]) } else { None }
+    }
+
+    /// 取得餘氣。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{EarthlyBranch, HeavenlyStems};
+    ///
+    /// assert_eq!(
+    ///     Some(HeavenlyStems::Tenth),
+    ///     EarthlyBranch::Fifth.hidden_stems().residual_qi()
+    /// );
+    /// assert_eq!(None, EarthlyBranch::Seventh.hidden_stems().residual_qi());
+    /// ```
+    #[inline]
+    pub const fn residual_qi(self) -> Option<HeavenlyStems> {
+        if self.len > 2 { Some(self.stems[2]) } else { None }
+    }
+
+    /// 以切片的形式取得所有藏干，依序為本氣、中氣、餘氣。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{EarthlyBranch, HeavenlyStems};
+    ///
+    /// let hidden_stems = EarthlyBranch::Seventh.hidden_stems();
+    ///
+    /// assert_eq!(
+    ///     [HeavenlyStems::Fourth, HeavenlyStems::Sixth],
+    ///     hidden_stems.as_slice()
+    /// );
+    /// ```
+    #[inline]
+    pub fn as_slice(&self) -> &[HeavenlyStems] {
+        &self.stems[..self.len as usize]
+    }
+
+    /// 取得各藏干依慣例分配的力量百分比，順序和 `as_slice` 相同，總和為 `100`。
+    ///
+    /// 此比例是常見的簡化慣例：有三個藏干時，本氣、中氣、餘氣依序為 `60`、`30`、`10`；只有本氣和中氣時為 `70`、`30`；只有本氣時為 `100`。它並不是由人元司令分野的天數推算而來，不同流派使用的比例可能不同；若需要人元司令分野的天數，請使用 `EarthlyBranch::ruling_stems`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::EarthlyBranch;
+    ///
+    /// assert_eq!(
+    ///     [60, 30, 10],
+    ///     EarthlyBranch::Fifth.hidden_stems().conventional_weights()
+    /// );
+    /// assert_eq!(
+    ///     [70, 30],
+    ///     EarthlyBranch::Seventh.hidden_stems().conventional_weights()
+    /// );
+    /// assert_eq!(
+    ///     [100],
+    ///     EarthlyBranch::First.hidden_stems().conventional_weights()
+    /// );
+    /// ```
+    #[inline]
+    pub fn conventional_weights(&self) -> &[u8] {
+        &self.weights[..self.len as usize]
+    }
+}
+
+impl EarthlyBranch {
+    /// 取得地支藏干。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{EarthlyBranch, HeavenlyStems};
+    ///
+    /// let hidden_stems = EarthlyBranch::Third.hidden_stems();
+    ///
+    /// assert_eq!(HeavenlyStems::First, hidden_stems.main_qi());
+    /// assert_eq!(Some(HeavenlyStems::Third), hidden_stems.middle_qi());
+    /// assert_eq!(Some(HeavenlyStems::Fifth), hidden_stems.residual_qi());
+    /// ```
+    #[inline]
+    pub const fn hidden_stems(self) -> HiddenStems {
+        HIDDEN_STEMS[(self.ordinal() - 1) as usize]
+    }
+}
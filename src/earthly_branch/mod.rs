@@ -2,7 +2,10 @@
 mod ba_zi_weight;
 mod built_in_traits;
 mod chinese;
+mod hidden_stems;
 mod parse;
+mod relations;
+mod ruling_stems;
 
 use core::{
     fmt::{self, Display, Formatter},
@@ -12,8 +15,10 @@ use core::{
 use chinese::{THE_EARTHLY_BRANCHES, THE_EARTHLY_BRANCHES_CHAR};
 use chrono::prelude::*;
 use enum_ordinalize::Ordinalize;
+pub use hidden_stems::*;
+pub use ruling_stems::*;
 
-use crate::Zodiac;
+use crate::{FiveElements, YinYang, Zodiac};
 
 /// 列舉中國十二地支：子、丑、寅、卯、辰、巳、午、未、申、酉、戌、亥。
 #[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash, Ordinalize)]
@@ -183,4 +188,40 @@ impl EarthlyBranch {
     pub const fn to_zodiac(self) -> Zodiac {
         unsafe { transmute(self) }
     }
+
+    /// 取得地支的五行。寅卯屬木、巳午屬火、申酉屬金、亥子屬水，辰戌丑未屬土。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{EarthlyBranch, FiveElements};
+    ///
+    /// assert_eq!(FiveElements::Earth, EarthlyBranch::Fifth.to_five_elements());
+    /// assert_eq!(FiveElements::Water, EarthlyBranch::First.to_five_elements());
+    /// ```
+    #[inline]
+    pub const fn to_five_elements(self) -> FiveElements {
+        match self {
+            Self::Third | Self::Fourth => FiveElements::Wood,
+            Self::Sixth | Self::Seventh => FiveElements::Fire,
+            Self::Second | Self::Fifth | Self::Eighth | Self::Eleventh => FiveElements::Earth,
+            Self::Ninth | Self::Tenth => FiveElements::Metal,
+            Self::Twelfth | Self::First => FiveElements::Water,
+        }
+    }
+
+    /// 取得地支的陰陽。子、寅、辰、午、申、戌為陽，丑、卯、巳、未、酉、亥為陰。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{EarthlyBranch, YinYang};
+    ///
+    /// assert_eq!(YinYang::Yang, EarthlyBranch::Fifth.to_yin_yang());
+    /// assert_eq!(YinYang::Yin, EarthlyBranch::Twelfth.to_yin_yang());
+    /// ```
+    #[inline]
+    pub const fn to_yin_yang(self) -> YinYang {
+        if self.ordinal() & 1 == 1 { YinYang::Yang } else { YinYang::Yin }
+    }
 }
@@ -0,0 +1,249 @@
+use super::EarthlyBranch;
+use crate::FiveElements;
+
+/// 三合局從子到亥依序對應的五行(申子辰水、巳酉丑金、寅午戌火、亥卯未木)，以地支序數除以四的餘數為索引。
+const THREE_HARMONY_FIVE_ELEMENTS: [FiveElements; 4] =
+    [FiveElements::Water, FiveElements::Metal, FiveElements::Fire, FiveElements::Wood];
+
+/// 三會方(寅卯辰木、巳午未火、申酉戌金、亥子丑水)對應的五行。
+const THREE_MEETING_FIVE_ELEMENTS: [FiveElements; 4] =
+    [FiveElements::Wood, FiveElements::Fire, FiveElements::Metal, FiveElements::Water];
+
+/// 從子到亥，每個地支所刑的地支。
+const PUNISHMENTS: [EarthlyBranch; 12] = [
+    EarthlyBranch::Fourth,   // 子刑卯
+    EarthlyBranch::Eleventh, // 丑刑戌
+    EarthlyBranch::Sixth,    // 寅刑巳
+    EarthlyBranch::First,    // 卯刑子
+    EarthlyBranch::Fifth,    // 辰自刑
+    EarthlyBranch::Ninth,    // 巳刑申
+    EarthlyBranch::Seventh,  // 午自刑
+    EarthlyBranch::Second,   // 未刑丑
+    EarthlyBranch::Third,    // 申刑寅
+    EarthlyBranch::Tenth,    // 酉自刑
+    EarthlyBranch::Eighth,   // 戌刑未
+    EarthlyBranch::Twelfth,  // 亥自刑
+];
+
+/// 地支之間的合、沖、刑、害、破。
+impl EarthlyBranch {
+    /// 判斷兩個地支是否六合，若是則回傳合化的五行。子丑合土、寅亥合木、卯戌合火、辰酉合金、巳申合水、午未合土。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{EarthlyBranch, FiveElements};
+    ///
+    /// assert_eq!(
+    ///     Some(FiveElements::Wood),
+    ///     EarthlyBranch::Third.combines_with(EarthlyBranch::Twelfth)
+    /// );
+    /// assert_eq!(None, EarthlyBranch::Third.combines_with(EarthlyBranch::Fourth));
+    /// ```
+    #[inline]
+    pub const fn combines_with(self, other: EarthlyBranch) -> Option<FiveElements> {
+        let a = self.ordinal() - 1;
+        let b = other.ordinal() - 1;
+
+        if (a + b) % 12 != 1 {
+            return None;
+        }
+
+        // 以六合中較小的地支來決定五行
+        Some(match if a < b { a } else { b } {
+            0 | 6 => FiveElements::Earth,
+            2 => FiveElements::Wood,
+            3 => FiveElements::Fire,
+            4 => FiveElements::Metal,
+            _ => FiveElements::Water,
+        })
+    }
+
+    /// 判斷兩個不同的地支是否屬於同一個三合局(申子辰水局、巳酉丑金局、寅午戌火局、亥卯未木局)，若是則回傳合局的五行。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{EarthlyBranch, FiveElements};
+    ///
+    /// assert_eq!(
+    ///     Some(FiveElements::Fire),
+    ///     EarthlyBranch::Third.three_harmony_with(EarthlyBranch::Seventh)
+    /// );
+    /// assert_eq!(
+    ///     None,
+    ///     EarthlyBranch::Third.three_harmony_with(EarthlyBranch::Third)
+    /// );
+    /// assert_eq!(
+    ///     None,
+    ///     EarthlyBranch::Third.three_harmony_with(EarthlyBranch::Fourth)
+    /// );
+    /// ```
+    #[inline]
+    pub const fn three_harmony_with(self, other: EarthlyBranch) -> Option<FiveElements> {
+        let a = self.ordinal() - 1;
+        let b = other.ordinal() - 1;
+
+        // 同一局的地支，序數除以四的餘數相同
+        if a != b && a % 4 == b % 4 {
+            Some(THREE_HARMONY_FIVE_ELEMENTS[(a % 4) as usize])
+        } else {
+            None
+        }
+    }
+
+    /// 判斷兩個地支是否六沖。子午、丑未、寅申、卯酉、辰戌、巳亥相沖。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::EarthlyBranch;
+    ///
+    /// assert!(EarthlyBranch::First.clashes_with(EarthlyBranch::Seventh));
+    /// assert!(!EarthlyBranch::First.clashes_with(EarthlyBranch::Second));
+    /// ```
+    #[inline]
+    pub const fn clashes_with(self, other: EarthlyBranch) -> bool {
+        (self.ordinal() + 12 - other.ordinal()) % 12 == 6
+    }
+
+    /// 判斷兩個地支是否相害。子未、丑午、寅巳、卯辰、申亥、酉戌相害。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::EarthlyBranch;
+    ///
+    /// assert!(EarthlyBranch::First.harms(EarthlyBranch::Eighth));
+    /// assert!(!EarthlyBranch::First.harms(EarthlyBranch::Seventh));
+    /// ```
+    #[inline]
+    pub const fn harms(self, other: EarthlyBranch) -> bool {
+        (self.ordinal() - 1 + other.ordinal() - 1) % 12 == 7
+    }
+
+    /// 判斷此地支是否刑 `other`。寅刑巳、巳刑申、申刑寅，丑刑戌、戌刑未、未刑丑，子卯相刑，辰、午、酉、亥自刑。
+    ///
+    /// 寅巳申和丑戌未的刑有方向性，若要判斷兩個地支之間是否有刑，需檢查兩個方向。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::EarthlyBranch;
+    ///
+    /// assert!(EarthlyBranch::Third.punishes(EarthlyBranch::Sixth));
+    /// assert!(!EarthlyBranch::Sixth.punishes(EarthlyBranch::Third));
+    /// assert!(EarthlyBranch::Fifth.punishes(EarthlyBranch::Fifth));
+    /// assert!(!EarthlyBranch::First.punishes(EarthlyBranch::First));
+    /// ```
+    #[inline]
+    pub const fn punishes(self, other: EarthlyBranch) -> bool {
+        PUNISHMENTS[(self.ordinal() - 1) as usize] as u8 == other as u8
+    }
+
+    /// 判斷兩個地支是否相破。子酉、寅亥、辰丑、午卯、申巳、戌未相破。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::EarthlyBranch;
+    ///
+    /// assert!(EarthlyBranch::First.breaks(EarthlyBranch::Tenth));
+    /// assert!(EarthlyBranch::Tenth.breaks(EarthlyBranch::First));
+    /// assert!(!EarthlyBranch::First.breaks(EarthlyBranch::Fourth));
+    /// ```
+    #[inline]
+    pub const fn breaks(self, other: EarthlyBranch) -> bool {
+        let a = self.ordinal() - 1;
+        let b = other.ordinal() - 1;
+
+        // 陽支往後數第九個地支即為其所破的陰支
+        if a & 1 == 0 { (a + 9) % 12 == b } else { (b + 9) % 12 == a }
+    }
+
+    /// 在多個地支中找出三合局(申子辰水局、巳酉丑金局、寅午戌火局、亥卯未木局)，回傳其五行。若有多組，不論地支的排列順序，一律依水局、金局、火局、木局的順序回傳第一個湊齊的局。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{EarthlyBranch, FiveElements};
+    ///
+    /// let branches = ["申", "午", "子", "辰"].map(|s| EarthlyBranch::parse_str(s).unwrap());
+    ///
+    /// assert_eq!(
+    ///     Some(FiveElements::Water),
+    ///     EarthlyBranch::find_three_harmony(&branches)
+    /// );
+    /// assert_eq!(None, EarthlyBranch::find_three_harmony(&branches[..3]));
+    /// ```
+    #[inline]
+    pub const fn find_three_harmony(earthly_branches: &[EarthlyBranch]) -> Option<FiveElements> {
+        // 每一局的三個地支，序數除以四的餘數相同
+        let mut found = [0u8; 4];
+
+        let mut i = 0;
+
+        while i < earthly_branches.len() {
+            let index = earthly_branches[i].ordinal() - 1;
+
+            found[(index % 4) as usize] |= 1 << (index / 4);
+
+            i += 1;
+        }
+
+        let mut i = 0;
+
+        while i < 4 {
+            if found[i] == 0b111 {
+                return Some(THREE_HARMONY_FIVE_ELEMENTS[i]);
+            }
+
+            i += 1;
+        }
+
+        None
+    }
+
+    /// 在多個地支中找出三會方(寅卯辰東方木、巳午未南方火、申酉戌西方金、亥子丑北方水)，回傳其五行。若有多組，不論地支的排列順序，一律依木方、火方、金方、水方的順序回傳第一個湊齊的方。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{EarthlyBranch, FiveElements};
+    ///
+    /// let branches = ["子", "亥", "寅", "丑"].map(|s| EarthlyBranch::parse_str(s).unwrap());
+    ///
+    /// assert_eq!(
+    ///     Some(FiveElements::Water),
+    ///     EarthlyBranch::find_three_meeting(&branches)
+    /// );
+    /// assert_eq!(None, EarthlyBranch::find_three_meeting(&branches[..3]));
+    /// ```
+    #[inline]
+    pub const fn find_three_meeting(earthly_branches: &[EarthlyBranch]) -> Option<FiveElements> {
+        // 從寅開始，每連續三個地支為一方
+        let mut found = [0u8; 4];
+
+        let mut i = 0;
+
+        while i < earthly_branches.len() {
+            let index = (earthly_branches[i].ordinal() + 9) % 12;
+
+            found[(index / 3) as usize] |= 1 << (index % 3);
+
+            i += 1;
+        }
+
+        let mut i = 0;
+
+        while i < 4 {
+            if found[i] == 0b111 {
+                return Some(THREE_MEETING_FIVE_ELEMENTS[i]);
+            }
+
+            i += 1;
+        }
+
+        None
+    }
+}
@@ -0,0 +1,181 @@
+use super::EarthlyBranch;
+use crate::HeavenlyStems;
+
+/// 從子到亥共十二地支的人元司令分野，依司令的先後順序列出天干及其司令的天數。
+const RULING_STEMS: [RulingStems; 12] = [
+    // 子：壬10、癸20
+    RulingStems::new(&[(HeavenlyStems::Ninth, 10), (HeavenlyStems::Tenth, 20)]),
+    // 丑：癸9、辛3、己18
+    RulingStems::new(&[
+        (HeavenlyStems::Tenth, 9),
+        (HeavenlyStems::Eighth, 3),
+        (HeavenlyStems::Sixth, 18),
+    ]),
+    // 寅：戊7、丙7、甲16
+    RulingStems::new(&[
+        (HeavenlyStems::Fifth, 7),
+        (HeavenlyStems::Third, 7),
+        (HeavenlyStems::First, 16),
+    ]),
+    // 卯：甲10、乙20
+    RulingStems::new(&[(HeavenlyStems::First, 10), (HeavenlyStems::Second, 20)]),
+    // 辰：乙9、癸3、戊18
+    RulingStems::new(&[
+        (HeavenlyStems::Second, 9),
+        (HeavenlyStems::Tenth, 3),
+        (HeavenlyStems::Fifth, 18),
+    ]),
+    // 巳：戊5、庚9、丙16
+    RulingStems::new(&[
+        (HeavenlyStems::Fifth, 5),
+        (HeavenlyStems::Seventh, 9),
+        (HeavenlyStems::Third, 16),
+    ]),
+    // 午：丙10、己9、丁11
+    RulingStems::new(&[
+        (HeavenlyStems::Third, 10),
+        (HeavenlyStems::Sixth, 9),
+        (HeavenlyStems::Fourth, 11),
+    ]),
+    // 未：丁9、乙3、己18
+    RulingStems::new(&[
+        (HeavenlyStems::Fourth, 9),
+        (HeavenlyStems::Second, 3),
+        (HeavenlyStems::Sixth, 18),
+    ]),
+    // 申：戊7、壬7、庚16
+    RulingStems::new(&[
+        (HeavenlyStems::Fifth, 7),
+        (HeavenlyStems::Ninth, 7),
+        (HeavenlyStems::Seventh, 16),
+    ]),
+    // 酉：庚10、辛20
+    RulingStems::new(&[(HeavenlyStems::Seventh, 10), (HeavenlyStems::Eighth, 20)]),
+    // 戌：辛9、丁3、戊18
+    RulingStems::new(&[
+        (HeavenlyStems::Eighth, 9),
+        (HeavenlyStems::Fourth, 3),
+        (HeavenlyStems::Fifth, 18),
+    ]),
+    // 亥：戊7、甲5、壬18
+    RulingStems::new(&[
+        (HeavenlyStems::Fifth, 7),
+        (HeavenlyStems::First, 5),
+        (HeavenlyStems::Ninth, 18),
+    ]),
+];
+
+/// 地支的人元司令分野，也就是一個月(以三十天計)之中，各天干依序司令的天數。
+///
+/// 司令的天干不一定和藏干相同，例如子月先由壬司令十日，再由癸司令二十日，但子的藏干只有癸。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RulingStems {
+    stems: [HeavenlyStems; 3],
+    days:  [u8; 3],
+    len:   u8,
+}
+
+impl RulingStems {
+    /// 利用依司令先後排列的天干及其天數來產生 `RulingStems` 實體，天干的數量即為 `ruling_stems` 的長度。
+    const fn new(ruling_stems: &[(HeavenlyStems, u8)]) -> Self {
+        let (first, _) = ruling_stems[0];
+
+        let mut stems = [first; 3];
+        let mut days = [0; 3];
+
+        let mut i = 0;
+
+        while i < ruling_stems.len() {
+            (stems[i], days[i]) = ruling_stems[i];
+
+            i += 1;
+        }
+
+        Self {
+            stems,
+            days,
+            len: ruling_stems.len() as u8,
+        }
+    }
+
+    /// 以切片的形式取得所有司令的天干，依司令的先後排列，最後一個即為本氣。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{EarthlyBranch, HeavenlyStems};
+    ///
+    /// assert_eq!(
+    ///     [HeavenlyStems::Fifth, HeavenlyStems::Third, HeavenlyStems::First],
+    ///     EarthlyBranch::Third.ruling_stems().as_slice()
+    /// );
+    /// ```
+    #[inline]
+    pub fn as_slice(&self) -> &[HeavenlyStems] {
+        &self.stems[..self.len as usize]
+    }
+
+    /// 取得各天干司令的天數，順序和 `as_slice` 相同，總和為 `30`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::EarthlyBranch;
+    ///
+    /// assert_eq!([7, 7, 16], EarthlyBranch::Third.ruling_stems().days());
+    /// assert_eq!([10, 20], EarthlyBranch::First.ruling_stems().days());
+    /// ```
+    #[inline]
+    pub fn days(&self) -> &[u8] {
+        &self.days[..self.len as usize]
+    }
+
+    /// 取得某個天干司令的天數，沒有司令則回傳 `0`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{EarthlyBranch, HeavenlyStems};
+    ///
+    /// let ruling_stems = EarthlyBranch::Third.ruling_stems();
+    ///
+    /// assert_eq!(16, ruling_stems.days_of(HeavenlyStems::First));
+    /// assert_eq!(0, ruling_stems.days_of(HeavenlyStems::Second));
+    /// ```
+    #[inline]
+    pub const fn days_of(self, stem: HeavenlyStems) -> u8 {
+        let mut i = 0;
+
+        while i < self.len as usize {
+            if self.stems[i] as u8 == stem as u8 {
+                return self.days[i];
+            }
+
+            i += 1;
+        }
+
+        0
+    }
+}
+
+impl EarthlyBranch {
+    /// 取得地支的人元司令分野。
+    ///
+    /// 和 `hidden_stems` 的慣用力量百分比不同，這裡的天數直接取自人元司令分野，可以用來依出生日距離交節的天數判斷當令的天干。不同典籍的天數略有出入，此處採用最常見的版本。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{EarthlyBranch, HeavenlyStems};
+    ///
+    /// let ruling_stems = EarthlyBranch::Third.ruling_stems();
+    ///
+    /// assert_eq!(7, ruling_stems.days_of(HeavenlyStems::Fifth));
+    /// assert_eq!(7, ruling_stems.days_of(HeavenlyStems::Third));
+    /// assert_eq!(16, ruling_stems.days_of(HeavenlyStems::First));
+    /// ```
+    #[inline]
+    pub const fn ruling_stems(self) -> RulingStems {
+        RULING_STEMS[(self.ordinal() - 1) as usize]
+    }
+}
@@ -0,0 +1,21 @@
+use core::str::FromStr;
+
+use super::FiveElements;
+
+impl TryFrom<char> for FiveElements {
+    type Error = ();
+
+    #[inline]
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Self::from_char(value).ok_or(())
+    }
+}
+
+impl FromStr for FiveElements {
+    type Err = ();
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_str(s).ok_or(())
+    }
+}
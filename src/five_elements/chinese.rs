@@ -0,0 +1,7 @@
+/// 列舉五行：木、火、土、金、水。
+pub(super) const THE_FIVE_ELEMENTS: [(&str, &str); 5] =
+    [("木", "木"), ("火", "火"), ("土", "土"), ("金", "金"), ("水", "水")];
+
+/// 列舉五行：木、火、土、金、水。
+pub(super) const THE_FIVE_ELEMENTS_CHAR: [(char, char); 5] =
+    [('木', '木'), ('火', '火'), ('土', '土'), ('金', '金'), ('水', '水')];
@@ -0,0 +1,131 @@
+mod built_in_traits;
+mod chinese;
+mod parse;
+
+use core::fmt::{self, Display, Formatter};
+
+use chinese::{THE_FIVE_ELEMENTS, THE_FIVE_ELEMENTS_CHAR};
+use enum_ordinalize::Ordinalize;
+
+use crate::ChineseVariant;
+
+/// 列舉五行，依相生的順序排列：木、火、土、金、水。
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash, Ordinalize)]
+#[ordinalize(impl_trait = false)]
+#[ordinalize(from_ordinal_unsafe(
+    pub fn from_ordinal_unsafe,
+    doc = "透過整數來取得 `FiveElements` 列舉實體。",
+    doc = "# Safety",
+    doc = "必須先確認傳入的整數是合法的。",
+))]
+#[ordinalize(ordinal(pub fn ordinal, doc = "取得 `FiveElements` 列舉實體所代表的整數。"))]
+#[repr(u8)]
+pub enum FiveElements {
+    /// 木
+    Wood = 1,
+    /// 火
+    Fire,
+    /// 土
+    Earth,
+    /// 金
+    Metal,
+    /// 水
+    Water,
+}
+
+impl Display for FiveElements {
+    /// Formats the value using the given formatter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chinese_lunisolar_calendar::FiveElements;
+    /// assert_eq!("金", format!("{}", FiveElements::Metal));
+    /// assert_eq!("金", format!("{:#}", FiveElements::Metal));
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str(self.to_str(ChineseVariant::Simple))
+        } else {
+            f.write_str(self.to_str(ChineseVariant::Traditional))
+        }
+    }
+}
+
+/// 用以建立 `FiveElements` 列舉實體的關聯函數。
+impl FiveElements {
+    /// 透過木、火、土、金、水等字元來取得 `FiveElements` 列舉實體。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chinese_lunisolar_calendar::FiveElements;
+    /// assert_eq!(FiveElements::Metal, FiveElements::from_char('金').unwrap());
+    /// ```
+    #[inline]
+    pub const fn from_char(c: char) -> Option<Self> {
+        let len = THE_FIVE_ELEMENTS_CHAR.len();
+
+        let mut i = 0;
+
+        loop {
+            let t = THE_FIVE_ELEMENTS_CHAR[i];
+
+            if c == t.0 || c == t.1 {
+                return Some(unsafe { Self::from_ordinal_unsafe(i as u8 + 1) });
+            }
+
+            i += 1;
+
+            if i == len {
+                break;
+            }
+        }
+
+        None
+    }
+}
+
+/// 將 `FiveElements` 列舉實體轉成其它型別的方法。
+impl FiveElements {
+    /// 取得 `FiveElements` 列舉實體所代表的五行字串。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{ChineseVariant, FiveElements};
+    ///
+    /// assert_eq!("木", FiveElements::Wood.to_str(ChineseVariant::Traditional));
+    /// assert_eq!("木", FiveElements::Wood.to_str(ChineseVariant::Simple));
+    /// ```
+    #[inline]
+    pub const fn to_str(self, chinese_variant: ChineseVariant) -> &'static str {
+        let i = (self.ordinal() - 1) as usize;
+
+        match chinese_variant {
+            ChineseVariant::Simple => THE_FIVE_ELEMENTS[i].1,
+            ChineseVariant::Traditional => THE_FIVE_ELEMENTS[i].0,
+        }
+    }
+
+    /// 取得 `FiveElements` 列舉實體所代表的五行字元。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{ChineseVariant, FiveElements};
+    ///
+    /// assert_eq!('水', FiveElements::Water.to_char(ChineseVariant::Traditional));
+    /// assert_eq!('水', FiveElements::Water.to_char(ChineseVariant::Simple));
+    /// ```
+    #[inline]
+    pub const fn to_char(self, chinese_variant: ChineseVariant) -> char {
+        let i = (self.ordinal() - 1) as usize;
+
+        match chinese_variant {
+            ChineseVariant::Simple => THE_FIVE_ELEMENTS_CHAR[i].1,
+            ChineseVariant::Traditional => THE_FIVE_ELEMENTS_CHAR[i].0,
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use super::{FiveElements, THE_FIVE_ELEMENTS};
+
+/// 用以解析字串的關聯函數。
+impl FiveElements {
+    /// 透過木、火、土、金、水等字串來取得 `FiveElements` 列舉實體。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chinese_lunisolar_calendar::FiveElements;
+    /// assert_eq!(FiveElements::Earth, FiveElements::parse_str("土").unwrap());
+    /// ```
+    #[inline]
+    pub fn parse_str<S: AsRef<str>>(s: S) -> Option<Self> {
+        let s = s.as_ref();
+
+        for (i, t) in THE_FIVE_ELEMENTS.iter().enumerate() {
+            if s == t.0 || s == t.1 {
+                return Some(unsafe { Self::from_ordinal_unsafe(i as u8 + 1) });
+            }
+        }
+
+        None
+    }
+}
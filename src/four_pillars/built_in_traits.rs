@@ -0,0 +1,27 @@
+use core::str::FromStr;
+
+use super::FourPillars;
+use crate::{Sexagenary, SexagenaryError};
+
+impl From<FourPillars> for [Sexagenary; 4] {
+    #[inline]
+    fn from(value: FourPillars) -> Self {
+        value.to_array()
+    }
+}
+
+impl From<[Sexagenary; 4]> for FourPillars {
+    #[inline]
+    fn from(value: [Sexagenary; 4]) -> Self {
+        Self::new(value[0], value[1], value[2], value[3])
+    }
+}
+
+impl FromStr for FourPillars {
+    type Err = SexagenaryError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_str(s)
+    }
+}
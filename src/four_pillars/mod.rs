@@ -0,0 +1,311 @@
+mod built_in_traits;
+mod parse;
+mod search;
+mod ten_gods;
+
+use core::fmt::{self, Display, Formatter, Write};
+
+use chrono::{NaiveTime, Timelike};
+pub use search::*;
+pub use ten_gods::*;
+
+use crate::{
+    ChineseVariant, LateZiHourRule, LunisolarDate, LunisolarOutOfRangeError, MonthPillarMode,
+    Sexagenary, SolarDate,
+};
+
+/// 推算四柱時使用的規則。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FourPillarsOptions {
+    /// 年柱和月柱的推算方式，預設以「節」為分界。
+    pub month_pillar_mode: MonthPillarMode,
+    /// 晚子時(23:00 到 24:00)的歸屬方式，預設屬於隔天。
+    pub late_zi_hour_rule: LateZiHourRule,
+}
+
+impl FourPillarsOptions {
+    /// 建立 `FourPillarsOptions` 實體。
+    #[inline]
+    pub const fn new(
+        month_pillar_mode: MonthPillarMode,
+        late_zi_hour_rule: LateZiHourRule,
+    ) -> Self {
+        Self {
+            month_pillar_mode,
+            late_zi_hour_rule,
+        }
+    }
+}
+
+impl Default for FourPillarsOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::new(MonthPillarMode::SolarTerm, LateZiHourRule::NextDay)
+    }
+}
+
+/// 四柱八字，由年柱、月柱、日柱、時柱四組干支組成。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FourPillars {
+    year:  Sexagenary,
+    month: Sexagenary,
+    day:   Sexagenary,
+    hour:  Sexagenary,
+}
+
+impl Display for FourPillars {
+    /// Formats the value using the given formatter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{FourPillars, FourPillarsOptions, SolarDate};
+    /// use chrono::prelude::*;
+    ///
+    /// let four_pillars = FourPillars::from_solar_date_time(
+    ///     SolarDate::from_ymd(2024, 2, 10).unwrap(),
+    ///     NaiveTime::from_hms_opt(0, 30, 0).unwrap(),
+    ///     FourPillarsOptions::default(),
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!("甲辰年 丙寅月 甲辰日 甲子時", format!("{}", four_pillars));
+    /// assert_eq!("甲辰年 丙寅月 甲辰日 甲子时", format!("{:#}", four_pillars));
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let chinese_variant =
+            if f.alternate() { ChineseVariant::Simple } else { ChineseVariant::Traditional };
+
+        let units = match chinese_variant {
+            ChineseVariant::Simple => ['年', '月', '日', '时'],
+            ChineseVariant::Traditional => ['年', '月', '日', '時'],
+        };
+
+        for (i, (pillar, unit)) in self.to_array().into_iter().zip(units).enumerate() {
+            if i > 0 {
+                f.write_char(' ')?;
+            }
+
+            f.write_str(pillar.to_str())?;
+            f.write_char(unit)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// 用以建立 `FourPillars` 實體的關聯函數。
+impl FourPillars {
+    /// 直接利用年柱、月柱、日柱、時柱來產生 `FourPillars` 實體。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{FourPillars, Sexagenary};
+    ///
+    /// let four_pillars = FourPillars::new(
+    ///     Sexagenary::parse_str("甲辰").unwrap(),
+    ///     Sexagenary::parse_str("丙寅").unwrap(),
+    ///     Sexagenary::parse_str("甲辰").unwrap(),
+    ///     Sexagenary::parse_str("甲子").unwrap(),
+    /// );
+    /// ```
+    #[inline]
+    pub const fn new(
+        year: Sexagenary,
+        month: Sexagenary,
+        day: Sexagenary,
+        hour: Sexagenary,
+    ) -> Self {
+        Self {
+            year,
+            month,
+            day,
+            hour,
+        }
+    }
+
+    /// 利用農曆日期和時間來推算四柱。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{
+    ///     FourPillars, FourPillarsOptions, LunisolarDate,
+    /// };
+    /// use chrono::prelude::*;
+    ///
+    /// let four_pillars = FourPillars::from_lunisolar_date_time(
+    ///     LunisolarDate::from_ymd(2008, 7, false, 8).unwrap(),
+    ///     NaiveTime::from_hms_opt(20, 8, 0).unwrap(),
+    ///     FourPillarsOptions::default(),
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!("戊子年 庚申月 庚辰日 丙戌時", four_pillars.to_string());
+    /// ```
+    #[inline]
+    pub fn from_lunisolar_date_time<T: Timelike>(
+        lunisolar_date: LunisolarDate,
+        time: T,
+        options: FourPillarsOptions,
+    ) -> Result<Self, LunisolarOutOfRangeError> {
+        // `time` 會用到好幾次，先轉成 `NaiveTime`。交節時刻只精確到分鐘，所以秒數可以忽略
+        let time = match NaiveTime::from_hms_opt(time.hour(), time.minute(), 0) {
+            Some(time) => time,
+            None => unreachable!(),
+        };
+
+        // 晚子時不論屬於哪一天，時干都以隔天的日干來推算
+        let hour = lunisolar_date.hour_stem_branch(time);
+
+        // 晚子時屬於隔天時，只有日柱要以隔天來推算，年柱和月柱仍以實際的日期和時間來推算
+        let day =
+            if time.hour() == 23 && matches!(options.late_zi_hour_rule, LateZiHourRule::NextDay) {
+                lunisolar_date.day_stem_branch() + 1
+            } else {
+                lunisolar_date.day_stem_branch()
+            };
+
+        Ok(Self::new(
+            lunisolar_date.year_stem_branch(time, options.month_pillar_mode),
+            lunisolar_date.month_stem_branch(time, options.month_pillar_mode),
+            day,
+            hour,
+        ))
+    }
+
+    /// 利用西曆日期和時間來推算四柱。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{
+    ///     FourPillars, FourPillarsOptions, LateZiHourRule, MonthPillarMode,
+    ///     SolarDate,
+    /// };
+    /// use chrono::prelude::*;
+    ///
+    /// let solar_date = SolarDate::from_ymd(2024, 2, 3).unwrap();
+    /// let time = NaiveTime::from_hms_opt(23, 30, 0).unwrap();
+    ///
+    /// let four_pillars = FourPillars::from_solar_date_time(
+    ///     solar_date,
+    ///     time,
+    ///     FourPillarsOptions::default(),
+    /// )
+    /// .unwrap();
+    ///
+    /// // 2024-02-04 16:27 立春
+    /// assert_eq!("癸卯年 乙丑月 戊戌日 壬子時", four_pillars.to_string());
+    ///
+    /// let four_pillars = FourPillars::from_solar_date_time(
+    ///     solar_date,
+    ///     time,
+    ///     FourPillarsOptions::new(
+    ///         MonthPillarMode::SolarTerm,
+    ///         LateZiHourRule::CurrentDay,
+    ///     ),
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!("癸卯年 乙丑月 丁酉日 壬子時", four_pillars.to_string());
+    /// ```
+    #[inline]
+    pub fn from_solar_date_time<T: Timelike>(
+        solar_date: SolarDate,
+        time: T,
+        options: FourPillarsOptions,
+    ) -> Result<Self, LunisolarOutOfRangeError> {
+        Self::from_lunisolar_date_time(solar_date.to_lunisolar_date()?, time, options)
+    }
+}
+
+/// 將 `FourPillars` 實體轉成其它型別的方法。
+impl FourPillars {
+    /// 取得年柱。
+    #[inline]
+    pub const fn to_year_pillar(self) -> Sexagenary {
+        self.year
+    }
+
+    /// 取得月柱。
+    #[inline]
+    pub const fn to_month_pillar(self) -> Sexagenary {
+        self.month
+    }
+
+    /// 取得日柱。
+    #[inline]
+    pub const fn to_day_pillar(self) -> Sexagenary {
+        self.day
+    }
+
+    /// 取得時柱。
+    #[inline]
+    pub const fn to_hour_pillar(self) -> Sexagenary {
+        self.hour
+    }
+
+    /// 依照年、月、日、時的順序，將四柱轉成陣列。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{FourPillars, Sexagenary};
+    ///
+    /// let jia_zi = Sexagenary::parse_str("甲子").unwrap();
+    ///
+    /// let four_pillars =
+    ///     FourPillars::new(jia_zi, jia_zi + 2, jia_zi + 4, jia_zi + 6);
+    ///
+    /// assert_eq!(
+    ///     [jia_zi, jia_zi + 2, jia_zi + 4, jia_zi + 6],
+    ///     four_pillars.to_array()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn to_array(self) -> [Sexagenary; 4] {
+        [self.year, self.month, self.day, self.hour]
+    }
+}
+
+/// 四柱的反查方法。
+impl FourPillars {
+    /// 在支援的日期範圍(1901-02-19 到 2101-01-28)中，依序找出所有與此四柱相符的西曆日期和時段。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{FourPillars, FourPillarsOptions, SolarDate};
+    /// use chrono::prelude::*;
+    ///
+    /// let four_pillars = FourPillars::parse_str("甲辰 丙寅 甲辰 甲子").unwrap();
+    ///
+    /// let mut search = four_pillars.search(FourPillarsOptions::default());
+    ///
+    /// assert_eq!(
+    ///     SolarDate::from_ymd(1964, 2, 25).unwrap(),
+    ///     search.next().unwrap().solar_date()
+    /// );
+    ///
+    /// let four_pillars_match = search.next().unwrap();
+    ///
+    /// assert_eq!(SolarDate::from_ymd(2024, 2, 10).unwrap(), four_pillars_match.solar_date());
+    /// assert_eq!(
+    ///     NaiveDate::from_ymd_opt(2024, 2, 9).unwrap().and_hms_opt(23, 0, 0).unwrap(),
+    ///     four_pillars_match.start()
+    /// );
+    /// assert_eq!(
+    ///     NaiveDate::from_ymd_opt(2024, 2, 10).unwrap().and_hms_opt(1, 0, 0).unwrap(),
+    ///     four_pillars_match.end()
+    /// );
+    ///
+    /// assert!(search.next().is_none());
+    /// ```
+    #[inline]
+    pub fn search(self, options: FourPillarsOptions) -> FourPillarsSearch {
+        FourPillarsSearch::new(self, options)
+    }
+}
@@ -0,0 +1,38 @@
+use super::FourPillars;
+use crate::{Sexagenary, SexagenaryError};
+
+/// 用以解析字串的關聯函數。
+impl FourPillars {
+    /// 透過以空白分隔的四組干支字串來取得 `FourPillars` 實體。每組干支後面可以加上年、月、日、時(时)。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::FourPillars;
+    ///
+    /// let four_pillars_1 = FourPillars::parse_str("甲辰 丙寅 甲辰 甲子").unwrap();
+    /// let four_pillars_2 = FourPillars::parse_str("甲辰年 丙寅月 甲辰日 甲子時").unwrap();
+    ///
+    /// assert_eq!(four_pillars_1, four_pillars_2);
+    /// ```
+    pub fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, SexagenaryError> {
+        let mut pillars = s.as_ref().split_whitespace();
+
+        let mut parse_pillar = |units: &[char]| {
+            let pillar = pillars.next().ok_or(SexagenaryError)?;
+
+            Sexagenary::parse_str(pillar.strip_suffix(units).unwrap_or(pillar))
+        };
+
+        let year = parse_pillar(&['年'])?;
+        let month = parse_pillar(&['月'])?;
+        let day = parse_pillar(&['日'])?;
+        let hour = parse_pillar(&['時', '时'])?;
+
+        if pillars.next().is_some() {
+            return Err(SexagenaryError);
+        }
+
+        Ok(Self::new(year, month, day, hour))
+    }
+}
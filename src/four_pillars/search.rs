@@ -0,0 +1,213 @@
+use core::iter::FusedIterator;
+
+use chrono::{NaiveDate, NaiveDateTime};
+
+use super::{FourPillars, FourPillarsOptions};
+use crate::{
+    EarthlyBranch, LateZiHourRule, LunisolarDate, MIN_LUNISOLAR_DATE_IN_SOLAR_DATE,
+    MonthPillarMode, Sexagenary, SolarDate,
+};
+
+/// 與四柱相符的一個西曆日期和時段。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FourPillarsMatch {
+    solar_date: SolarDate,
+    start:      NaiveDateTime,
+    end:        NaiveDateTime,
+}
+
+impl FourPillarsMatch {
+    /// 取得日柱所對應的西曆日期。當晚子時屬於隔天時，子時的開始時間會在此日期的前一天。
+    #[inline]
+    pub const fn solar_date(&self) -> SolarDate {
+        self.solar_date
+    }
+
+    /// 取得時段的開始時間(包含)。
+    #[inline]
+    pub const fn start(&self) -> NaiveDateTime {
+        self.start
+    }
+
+    /// 取得時段的結束時間(不包含)。
+    #[inline]
+    pub const fn end(&self) -> NaiveDateTime {
+        self.end
+    }
+}
+
+/// 在支援的日期範圍中，依序找出與四柱相符的西曆日期和時段的迭代器。
+#[derive(Debug, Clone)]
+pub struct FourPillarsSearch {
+    four_pillars: FourPillars,
+    options:      FourPillarsOptions,
+    next:         Option<LunisolarDate>,
+}
+
+impl FourPillarsSearch {
+    pub(super) fn new(four_pillars: FourPillars, options: FourPillarsOptions) -> Self {
+        let day = four_pillars.to_day_pillar();
+        let hour = four_pillars.to_hour_pillar();
+        let hour_branch = hour.to_earthly_branch();
+
+        // 時干由日干決定(五鼠遁)，不相符的話就不可能有任何結果
+        let mut possible =
+            Sexagenary::from_day_stem_and_hour_branch(day.to_heavenly_stems(), hour_branch) == hour;
+
+        // 晚子時屬於當天時，晚子時的時干是由隔天的日干決定
+        if hour_branch == EarthlyBranch::First
+            && matches!(options.late_zi_hour_rule, LateZiHourRule::CurrentDay)
+        {
+            possible |= Sexagenary::from_day_stem_and_hour_branch(
+                (day + 1).to_heavenly_stems(),
+                hour_branch,
+            ) == hour;
+        }
+
+        let next = if possible {
+            let min = match LunisolarDate::from_solar_date(MIN_LUNISOLAR_DATE_IN_SOLAR_DATE) {
+                Ok(lunisolar_date) => lunisolar_date,
+                Err(_) => unreachable!(),
+            };
+
+            // 日柱每六十天循環一次
+            min.checked_add_days((day - min.day_stem_branch()) as u32).ok()
+        } else {
+            None
+        };
+
+        Self {
+            four_pillars,
+            options,
+            next,
+        }
+    }
+
+    /// 找出 `start` 到 `end` 之間與四柱相符的時段。年柱和月柱可能在午夜或交節時刻改變，所以要在這些時間點切開來逐段檢查。
+    fn find_range(
+        &self,
+        start: NaiveDateTime,
+        end: NaiveDateTime,
+    ) -> Option<(NaiveDateTime, NaiveDateTime)> {
+        let mut points = [start; 4];
+        let mut len = 1;
+
+        let midnight = at_hour(end.date(), 0);
+
+        if start < midnight && midnight < end {
+            points[len] = midnight;
+            len += 1;
+        }
+
+        if let MonthPillarMode::SolarTerm = self.options.month_pillar_mode {
+            // 時段的開始時間不會早於 1901-02-18，也不會晚於 2101-01-29
+            let solar_term = match SolarDate::from_date(start.date()) {
+                Ok(solar_date) => unsafe { solar_date.solar_term_date_time_unsafe() },
+                Err(_) => unreachable!(),
+            };
+
+            if start < solar_term && solar_term < end {
+                points[len] = solar_term;
+                len += 1;
+            }
+        }
+
+        points[len] = end;
+        len += 1;
+
+        let points = &mut points[..len];
+
+        points.sort_unstable();
+
+        // 年柱和月柱只會隨著時間前進，所以相符的部分一定是連續的
+        let mut range: Option<(NaiveDateTime, NaiveDateTime)> = None;
+
+        for window in points.windows(2) {
+            if self.is_match(window[0]) {
+                range = Some(match range {
+                    Some((start, _)) => (start, window[1]),
+                    None => (window[0], window[1]),
+                });
+            }
+        }
+
+        range
+    }
+
+    /// 判斷某個時間的四柱是否相符。
+    #[inline]
+    fn is_match(&self, date_time: NaiveDateTime) -> bool {
+        SolarDate::from_date(date_time.date()).is_ok_and(|solar_date| {
+            FourPillars::from_solar_date_time(solar_date, date_time.time(), self.options)
+                .is_ok_and(|four_pillars| four_pillars == self.four_pillars)
+        })
+    }
+}
+
+impl Iterator for FourPillarsSearch {
+    type Item = FourPillarsMatch;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let lunisolar_date = self.next?;
+
+            self.next = lunisolar_date.checked_add_days(60).ok();
+
+            let solar_date = lunisolar_date.to_solar_date();
+            let date = solar_date.to_naive_date();
+
+            let hour_branch = self.four_pillars.to_hour_pillar().to_earthly_branch();
+
+            let ranges = if hour_branch == EarthlyBranch::First {
+                match self.options.late_zi_hour_rule {
+                    LateZiHourRule::NextDay => {
+                        let previous_date = match date.pred_opt() {
+                            Some(date) => date,
+                            None => unreachable!(),
+                        };
+
+                        [Some((at_hour(previous_date, 23), at_hour(date, 1))), None]
+                    },
+                    LateZiHourRule::CurrentDay => {
+                        let next_date = match date.succ_opt() {
+                            Some(date) => date,
+                            None => unreachable!(),
+                        };
+
+                        [
+                            Some((at_hour(date, 0), at_hour(date, 1))),
+                            Some((at_hour(date, 23), at_hour(next_date, 0))),
+                        ]
+                    },
+                }
+            } else {
+                // 丑時從 1:00 開始，每個時辰兩小時
+                let start_hour = (hour_branch.ordinal() as u32 - 1) * 2 - 1;
+
+                [Some((at_hour(date, start_hour), at_hour(date, start_hour + 2))), None]
+            };
+
+            // 晚子時屬於當天時，早子時和晚子時的時干不同，所以最多只有一個時段相符
+            let four_pillars_match =
+                ranges.into_iter().flatten().find_map(|(start, end)| self.find_range(start, end));
+
+            if let Some((start, end)) = four_pillars_match {
+                return Some(FourPillarsMatch {
+                    solar_date,
+                    start,
+                    end,
+                });
+            }
+        }
+    }
+}
+
+impl FusedIterator for FourPillarsSearch {}
+
+#[inline]
+fn at_hour(date: NaiveDate, hour: u32) -> NaiveDateTime {
+    match date.and_hms_opt(hour, 0, 0) {
+        Some(date_time) => date_time,
+        None => unreachable!(),
+    }
+}
@@ -0,0 +1,63 @@
+use super::FourPillars;
+use crate::{HeavenlyStems, Sexagenary, TenGods};
+
+/// 一柱干支相對於日主的十神。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PillarTenGods {
+    /// 天干的十神。日柱的天干即為日主，因此為 `None`。
+    pub heavenly_stems: Option<TenGods>,
+    /// 地支藏干的十神，依序為本氣、中氣、餘氣。
+    pub hidden_stems:   [Option<TenGods>; 3],
+}
+
+impl FourPillars {
+    /// 以日柱的天干為日主，依照年、月、日、時的順序計算四柱天干和地支藏干的十神。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{FourPillars, TenGods};
+    ///
+    /// let four_pillars = FourPillars::parse_str("甲辰 丙寅 甲辰 甲子").unwrap();
+    ///
+    /// let [year, month, day, hour] = four_pillars.to_ten_gods();
+    ///
+    /// assert_eq!(Some(TenGods::Companion), year.heavenly_stems);
+    /// assert_eq!(Some(TenGods::EatingGod), month.heavenly_stems);
+    /// assert_eq!(None, day.heavenly_stems);
+    /// assert_eq!(
+    ///     [
+    ///         Some(TenGods::IndirectWealth),
+    ///         Some(TenGods::RobWealth),
+    ///         Some(TenGods::DirectResource)
+    ///     ],
+    ///     day.hidden_stems
+    /// );
+    /// assert_eq!([Some(TenGods::DirectResource), None, None], hour.hidden_stems);
+    /// ```
+    #[inline]
+    pub const fn to_ten_gods(self) -> [PillarTenGods; 4] {
+        let day_master = self.day.to_heavenly_stems();
+
+        let mut day = PillarTenGods::new(self.day, day_master);
+
+        day.heavenly_stems = None;
+
+        [
+            PillarTenGods::new(self.year, day_master),
+            PillarTenGods::new(self.month, day_master),
+            day,
+            PillarTenGods::new(self.hour, day_master),
+        ]
+    }
+}
+
+impl PillarTenGods {
+    #[inline]
+    const fn new(sexagenary: Sexagenary, day_master: HeavenlyStems) -> Self {
+        Self {
+            heavenly_stems: Some(sexagenary.to_heavenly_stems().to_ten_gods(day_master)),
+            hidden_stems:   sexagenary.to_earthly_branch().hidden_stems().to_ten_gods(day_master),
+        }
+    }
+}
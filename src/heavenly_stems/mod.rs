@@ -1,12 +1,15 @@
 mod built_in_traits;
 mod chinese;
 mod parse;
+mod relations;
 
 use core::fmt::{self, Display, Formatter};
 
 use chinese::{THE_HEAVENLY_STEMS, THE_HEAVENLY_STEMS_CHAR};
 use enum_ordinalize::Ordinalize;
 
+use crate::{FiveElements, YinYang};
+
 /// 列舉中國十天干：甲、乙、丙、丁、戊、己、庚、辛、壬、癸。
 #[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash, Ordinalize)]
 #[ordinalize(impl_trait = false)]
@@ -119,4 +122,34 @@ impl HeavenlyStems {
 
         THE_HEAVENLY_STEMS_CHAR[i]
     }
+
+    /// 取得天干的五行。甲乙屬木、丙丁屬火、戊己屬土、庚辛屬金、壬癸屬水。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{FiveElements, HeavenlyStems};
+    ///
+    /// assert_eq!(FiveElements::Earth, HeavenlyStems::Fifth.to_five_elements());
+    /// assert_eq!(FiveElements::Water, HeavenlyStems::Tenth.to_five_elements());
+    /// ```
+    #[inline]
+    pub const fn to_five_elements(self) -> FiveElements {
+        unsafe { FiveElements::from_ordinal_unsafe((self.ordinal() - 1) / 2 + 1) }
+    }
+
+    /// 取得天干的陰陽。甲、丙、戊、庚、壬為陽，乙、丁、己、辛、癸為陰。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{HeavenlyStems, YinYang};
+    ///
+    /// assert_eq!(YinYang::Yang, HeavenlyStems::Fifth.to_yin_yang());
+    /// assert_eq!(YinYang::Yin, HeavenlyStems::Tenth.to_yin_yang());
+    /// ```
+    #[inline]
+    pub const fn to_yin_yang(self) -> YinYang {
+        if self.ordinal() & 1 == 1 { YinYang::Yang } else { YinYang::Yin }
+    }
 }
@@ -0,0 +1,59 @@
+use super::HeavenlyStems;
+use crate::FiveElements;
+
+/// 天干五合(甲己、乙庚、丙辛、丁壬、戊癸)合化的五行，以兩個天干中較前者的序數為索引。
+const FIVE_COMBINATIONS_FIVE_ELEMENTS: [FiveElements; 5] = [
+    FiveElements::Earth,
+    FiveElements::Metal,
+    FiveElements::Water,
+    FiveElements::Wood,
+    FiveElements::Fire,
+];
+
+/// 天干之間的合、沖。
+impl HeavenlyStems {
+    /// 判斷兩個天干是否五合，若是則回傳合化的五行。甲己合土、乙庚合金、丙辛合水、丁壬合木、戊癸合火。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{FiveElements, HeavenlyStems};
+    ///
+    /// assert_eq!(
+    ///     Some(FiveElements::Earth),
+    ///     HeavenlyStems::First.combines_with(HeavenlyStems::Sixth)
+    /// );
+    /// assert_eq!(None, HeavenlyStems::First.combines_with(HeavenlyStems::Second));
+    /// ```
+    #[inline]
+    pub const fn combines_with(self, other: HeavenlyStems) -> Option<FiveElements> {
+        let a = self.ordinal() - 1;
+        let b = other.ordinal() - 1;
+
+        if (a + 10 - b) % 10 == 5 {
+            Some(FIVE_COMBINATIONS_FIVE_ELEMENTS[(a % 5) as usize])
+        } else {
+            None
+        }
+    }
+
+    /// 判斷兩個天干是否相沖。甲庚、乙辛、丙壬、丁癸相沖，戊己居中央，不與其它天干相沖。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::HeavenlyStems;
+    ///
+    /// assert!(HeavenlyStems::First.clashes_with(HeavenlyStems::Seventh));
+    /// assert!(!HeavenlyStems::Fifth.clashes_with(HeavenlyStems::First));
+    /// ```
+    #[inline]
+    pub const fn clashes_with(self, other: HeavenlyStems) -> bool {
+        let a = self.ordinal() - 1;
+        let b = other.ordinal() - 1;
+
+        let (min, max) = if a < b { (a, b) } else { (b, a) };
+
+        min < 4 && max - min == 6
+    }
+}
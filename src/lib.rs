@@ -54,16 +54,32 @@ default-features = false
 pub extern crate chrono;
 
 mod earthly_branch;
+mod five_elements;
+mod four_pillars;
 mod heavenly_stems;
 mod lunar;
 mod lunisolar;
+mod na_yin;
+mod sexagenary;
 mod solar;
+mod tai_sui;
+mod ten_gods;
+mod weekday;
+mod yin_yang;
 mod zodiac;
 
 pub use chinese_variant::ChineseVariant;
 pub use earthly_branch::*;
+pub use five_elements::*;
+pub use four_pillars::*;
 pub use heavenly_stems::*;
 pub use lunar::*;
 pub use lunisolar::*;
+pub use na_yin::*;
+pub use sexagenary::*;
 pub use solar::*;
+pub use tai_sui::*;
+pub use ten_gods::*;
+pub use weekday::*;
+pub use yin_yang::*;
 pub use zodiac::*;
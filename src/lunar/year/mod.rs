@@ -1,15 +1,12 @@
 #[cfg(feature = "ba-zi-weight")]
 mod ba_zi_weight;
 mod built_in_traits;
-mod chinese;
 mod parse;
 
 use core::fmt::{self, Display, Formatter};
 
-use chinese::THE_LUNAR_YEARS;
-
 use super::LunarYearError;
-use crate::{EarthlyBranch, HeavenlyStems, Zodiac};
+use crate::{EarthlyBranch, FiveElements, HeavenlyStems, NaYin, Sexagenary, YinYang, Zodiac};
 
 /// 農曆年份，由天干加地支組成，六十年一輪。
 #[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
@@ -53,30 +50,27 @@ impl LunarYear {
         heavenly_stems: HeavenlyStems,
         earthly_branch: EarthlyBranch,
     ) -> Result<Self, LunarYearError> {
-        let h = heavenly_stems.ordinal();
-        let e = earthly_branch.ordinal();
-
-        let year_index = if h == e {
-            h
-        } else if h < e {
-            let diff = e - h;
-
-            if diff & 1 == 1 {
-                return Err(LunarYearError);
-            }
-
-            h + (12 - diff) * 5
-        } else {
-            let diff = h - e;
-
-            if diff & 1 == 1 {
-                return Err(LunarYearError);
-            }
-
-            e + diff * 6
-        } - 1;
+        match Sexagenary::from_stem_branch(heavenly_stems, earthly_branch) {
+            Ok(sexagenary) => Ok(Self::from_sexagenary(sexagenary)),
+            Err(_) => Err(LunarYearError),
+        }
+    }
 
-        Ok(LunarYear(year_index))
+    /// 透過干支來取得 `LunarYear` 實體。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{LunarYear, Sexagenary};
+    ///
+    /// let lunar_year =
+    ///     LunarYear::from_sexagenary(Sexagenary::parse_str("戊戌").unwrap());
+    ///
+    /// assert_eq!("戊戌", lunar_year.to_str());
+    /// ```
+    #[inline]
+    pub const fn from_sexagenary(sexagenary: Sexagenary) -> Self {
+        LunarYear(sexagenary.0)
     }
 }
 
@@ -97,7 +91,26 @@ impl LunarYear {
     /// ```
     #[inline]
     pub const fn to_str(self) -> &'static str {
-        THE_LUNAR_YEARS[self.0 as usize]
+        self.to_sexagenary().to_str()
+    }
+
+    /// 轉成干支。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{LunarYear, Sexagenary};
+    ///
+    /// let lunar_year = LunarYear::parse_str("戊戌").unwrap();
+    ///
+    /// assert_eq!(
+    ///     Sexagenary::parse_str("戊戌").unwrap(),
+    ///     lunar_year.to_sexagenary()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn to_sexagenary(self) -> Sexagenary {
+        Sexagenary(self.0)
     }
 
     /// 取得天干。
@@ -155,4 +168,55 @@ impl LunarYear {
     pub const fn to_zodiac(&self) -> Zodiac {
         self.to_earthly_branch().to_zodiac()
     }
+
+    /// 取得年份的五行，以天干的五行為準。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{FiveElements, LunarYear};
+    ///
+    /// let lunar_year = LunarYear::parse_str("甲辰").unwrap();
+    ///
+    /// assert_eq!(FiveElements::Wood, lunar_year.to_five_elements());
+    /// ```
+    #[inline]
+    pub const fn to_five_elements(&self) -> FiveElements {
+        self.to_heavenly_stems().to_five_elements()
+    }
+
+    /// 取得年份的陰陽，以天干的陰陽為準。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{LunarYear, YinYang};
+    ///
+    /// let lunar_year = LunarYear::parse_str("甲辰").unwrap();
+    ///
+    /// assert_eq!(YinYang::Yang, lunar_year.to_yin_yang());
+    /// ```
+    #[inline]
+    pub const fn to_yin_yang(&self) -> YinYang {
+        self.to_heavenly_stems().to_yin_yang()
+    }
+
+    /// 取得年份的納音。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::LunarYear;
+    ///
+    /// let lunar_year = LunarYear::parse_str("甲辰").unwrap();
+    ///
+    /// assert_eq!(
+    ///     "甲辰年 覆燈火",
+    ///     format!("{}年 {}", lunar_year, lunar_year.nayin())
+    /// );
+    /// ```
+    #[inline]
+    pub const fn nayin(&self) -> NaYin {
+        self.to_sexagenary().nayin()
+    }
 }
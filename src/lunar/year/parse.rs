@@ -1,4 +1,5 @@
-use super::{LunarYear, LunarYearError, THE_LUNAR_YEARS};
+use super::{LunarYear, LunarYearError};
+use crate::Sexagenary;
 
 /// 用以解析字串的關聯函數。
 impl LunarYear {
@@ -16,14 +17,9 @@ impl LunarYear {
     /// ```
     #[inline]
     pub fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, LunarYearError> {
-        let s = s.as_ref();
-
-        for (i, &t) in THE_LUNAR_YEARS.iter().enumerate() {
-            if s == t {
-                return Ok(LunarYear(i as u8));
-            }
+        match Sexagenary::parse_str(s) {
+            Ok(sexagenary) => Ok(Self::from_sexagenary(sexagenary)),
+            Err(_) => Err(LunarYearError),
         }
-
-        Err(LunarYearError)
     }
 }
@@ -0,0 +1,308 @@
+use super::LunisolarDate;
+use crate::{
+    LunarDay, LunarMonth, LunisolarDateError, LunisolarYear, MAX_YEAR_IN_SOLAR_CALENDAR,
+    MIN_YEAR_IN_SOLAR_CALENDAR, SolarYear,
+};
+
+/// 農曆月份加減時，閏月的計算方式。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LeapMonthPolicy {
+    /// 閏月不算是獨立的一個月，視同同數字的一般月份。例如閏四月加一個月為五月、閏四月加一年為隔年的四月。
+    #[default]
+    Merge,
+    /// 閏月算是獨立的一個月。例如四月加一個月為閏四月(若該年有閏四月)、閏四月加一年為隔年的閏四月(若隔年有閏四月)。
+    Separate,
+}
+
+/// 農曆月份或年份加減後，目標月份沒有該日(三十)或沒有對應的閏月時的處理方式。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LunarOverflowPolicy {
+    /// 改用最接近的日期。沒有三十就改用廿九；沒有對應的閏月就改用同數字的一般月份。
+    #[default]
+    Clamp,
+    /// 將超出的部分順延。沒有三十就改用下個月的初一；沒有對應的閏月就改用下一個月。
+    RollOver,
+    /// 回傳錯誤。
+    Error,
+}
+
+/// 農曆月份或年份加減的規則。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct LunarArithmeticPolicy {
+    /// 閏月的計算方式。
+    pub leap_month: LeapMonthPolicy,
+    /// 目標月份沒有該日或沒有對應的閏月時的處理方式。
+    pub overflow:   LunarOverflowPolicy,
+}
+
+impl LunarArithmeticPolicy {
+    /// 建立 `LunarArithmeticPolicy` 實體。
+    #[inline]
+    pub const fn new(leap_month: LeapMonthPolicy, overflow: LunarOverflowPolicy) -> Self {
+        Self {
+            leap_month,
+            overflow,
+        }
+    }
+}
+
+/// 農曆月份和年份的加減方法。
+impl LunisolarDate {
+    /// 計算往後(或往前，若 `months` 為負數) `months` 個農曆月的同一天。閏月是否算是獨立的一個月，以及目標月份沒有三十時的處理方式，皆由 `policy` 決定。`months` 為 `0` 時一律回傳原日期。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{
+    ///     LeapMonthPolicy, LunarArithmeticPolicy, LunarOverflowPolicy,
+    ///     LunisolarDate,
+    /// };
+    ///
+    /// let lunisolar_date = LunisolarDate::from_ymd(2023, 2, false, 30).unwrap();
+    ///
+    /// let merge = LunarArithmeticPolicy::new(
+    ///     LeapMonthPolicy::Merge,
+    ///     LunarOverflowPolicy::Clamp,
+    /// );
+    /// let separate = LunarArithmeticPolicy::new(
+    ///     LeapMonthPolicy::Separate,
+    ///     LunarOverflowPolicy::Clamp,
+    /// );
+    ///
+    /// assert_eq!(
+    ///     LunisolarDate::from_ymd(2023, 3, false, 29).unwrap(),
+    ///     lunisolar_date.checked_add_lunar_months(1, merge).unwrap()
+    /// );
+    /// assert_eq!(
+    ///     LunisolarDate::from_ymd(2023, 2, true, 29).unwrap(),
+    ///     lunisolar_date.checked_add_lunar_months(1, separate).unwrap()
+    /// );
+    /// ```
+    pub const fn checked_add_lunar_months(
+        self,
+        months: i32,
+        policy: LunarArithmeticPolicy,
+    ) -> Result<LunisolarDate, LunisolarDateError> {
+        if months == 0 {
+            return Ok(self);
+        }
+
+        let mut year = self.lunisolar_year.to_u16() as i32;
+
+        match policy.leap_month {
+            LeapMonthPolicy::Merge => {
+                let offset = match (self.lunar_month.to_u8() as i32 - 1).checked_add(months) {
+                    Some(offset) => offset,
+                    None => return Err(LunisolarDateError::OutOfRange),
+                };
+
+                year += offset.div_euclid(12);
+
+                let lunisolar_year = match lunisolar_year_from_i32(year) {
+                    Ok(lunisolar_year) => lunisolar_year,
+                    Err(error) => return Err(error),
+                };
+
+                let lunar_month = unsafe {
+                    LunarMonth::from_u8_with_leap_unsafe(offset.rem_euclid(12) as u8 + 1, false)
+                };
+
+                resolve(lunisolar_year, lunar_month, self.lunar_day, policy.overflow)
+            },
+            LeapMonthPolicy::Separate => {
+                let mut lunisolar_year = self.lunisolar_year;
+
+                let index = lunisolar_year.get_lunar_month_index(self.lunar_month) as i32;
+
+                let mut offset = match index.checked_add(months) {
+                    Some(offset) => offset,
+                    None => return Err(LunisolarDateError::OutOfRange),
+                };
+
+                while offset < 0 {
+                    year -= 1;
+
+                    lunisolar_year = match lunisolar_year_from_i32(year) {
+                        Ok(lunisolar_year) => lunisolar_year,
+                        Err(error) => return Err(error),
+                    };
+
+                    offset += lunisolar_year.get_total_months() as i32;
+                }
+
+                loop {
+                    let total_months = lunisolar_year.get_total_months() as i32;
+
+                    if offset < total_months {
+                        break;
+                    }
+
+                    offset -= total_months;
+                    year += 1;
+
+                    lunisolar_year = match lunisolar_year_from_i32(year) {
+                        Ok(lunisolar_year) => lunisolar_year,
+                        Err(error) => return Err(error),
+                    };
+                }
+
+                let lunar_month = lunisolar_year.get_lunar_month_by_index(offset as u8);
+
+                resolve(lunisolar_year, lunar_month, self.lunar_day, policy.overflow)
+            },
+        }
+    }
+
+    /// 計算往後(或往前，若 `years` 為負數) `years` 個農曆年的同月同日。閏月是否要對應到目標年份的閏月，以及目標月份沒有三十或沒有對應的閏月時的處理方式，皆由 `policy` 決定。`years` 為 `0` 時一律回傳原日期。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{
+    ///     LeapMonthPolicy, LunarArithmeticPolicy, LunarOverflowPolicy,
+    ///     LunisolarDate,
+    /// };
+    ///
+    /// let lunisolar_date = LunisolarDate::from_ymd(2023, 2, true, 29).unwrap();
+    ///
+    /// let clamp = LunarArithmeticPolicy::new(
+    ///     LeapMonthPolicy::Separate,
+    ///     LunarOverflowPolicy::Clamp,
+    /// );
+    /// let roll_over = LunarArithmeticPolicy::new(
+    ///     LeapMonthPolicy::Separate,
+    ///     LunarOverflowPolicy::RollOver,
+    /// );
+    /// let error = LunarArithmeticPolicy::new(
+    ///     LeapMonthPolicy::Separate,
+    ///     LunarOverflowPolicy::Error,
+    /// );
+    ///
+    /// assert_eq!(
+    ///     LunisolarDate::from_ymd(2024, 2, false, 29).unwrap(),
+    ///     lunisolar_date.checked_add_lunar_years(1, clamp).unwrap()
+    /// );
+    /// assert_eq!(
+    ///     LunisolarDate::from_ymd(2024, 3, false, 29).unwrap(),
+    ///     lunisolar_date.checked_add_lunar_years(1, roll_over).unwrap()
+    /// );
+    /// assert!(lunisolar_date.checked_add_lunar_years(1, error).is_err());
+    /// ```
+    pub const fn checked_add_lunar_years(
+        self,
+        years: i32,
+        policy: LunarArithmeticPolicy,
+    ) -> Result<LunisolarDate, LunisolarDateError> {
+        if years == 0 {
+            return Ok(self);
+        }
+
+        let year = match (self.lunisolar_year.to_u16() as i32).checked_add(years) {
+            Some(year) => year,
+            None => return Err(LunisolarDateError::OutOfRange),
+        };
+
+        let lunisolar_year = match lunisolar_year_from_i32(year) {
+            Ok(lunisolar_year) => lunisolar_year,
+            Err(error) => return Err(error),
+        };
+
+        let lunar_month = match policy.leap_month {
+            LeapMonthPolicy::Merge => unsafe {
+                LunarMonth::from_u8_with_leap_unsafe(self.lunar_month.to_u8(), false)
+            },
+            LeapMonthPolicy::Separate => self.lunar_month,
+        };
+
+        resolve(lunisolar_year, lunar_month, self.lunar_day, policy.overflow)
+    }
+}
+
+#[inline]
+const fn lunisolar_year_from_i32(year: i32) -> Result<LunisolarYear, LunisolarDateError> {
+    if year >= MIN_YEAR_IN_SOLAR_CALENDAR as i32 && year <= MAX_YEAR_IN_SOLAR_CALENDAR as i32 {
+        Ok(unsafe { LunisolarYear::from_solar_year_unsafe(SolarYear::from_u16(year as u16)) })
+    } else {
+        Err(LunisolarDateError::OutOfRange)
+    }
+}
+
+/// 依照 `overflow` 的規則，將可能不存在的農曆年月日轉成 `LunisolarDate` 實體。
+const fn resolve(
+    lunisolar_year: LunisolarYear,
+    lunar_month: LunarMonth,
+    lunar_day: LunarDay,
+    overflow: LunarOverflowPolicy,
+) -> Result<LunisolarDate, LunisolarDateError> {
+    let days = match lunar_month.get_total_days(lunisolar_year) {
+        Some(days) => days,
+        None => {
+            // 該年沒有此閏月
+            let lunar_month =
+                unsafe { LunarMonth::from_u8_with_leap_unsafe(lunar_month.to_u8(), false) };
+
+            return match overflow {
+                LunarOverflowPolicy::Clamp => {
+                    resolve(lunisolar_year, lunar_month, lunar_day, overflow)
+                },
+                LunarOverflowPolicy::RollOver => {
+                    let index = lunisolar_year.get_lunar_month_index(lunar_month) + 1;
+
+                    if index < lunisolar_year.get_total_months() {
+                        resolve(
+                            lunisolar_year,
+                            lunisolar_year.get_lunar_month_by_index(index),
+                            lunar_day,
+                            overflow,
+                        )
+                    } else {
+                        match lunisolar_year_from_i32(lunisolar_year.to_u16() as i32 + 1) {
+                            Ok(lunisolar_year) => {
+                                resolve(lunisolar_year, LunarMonth::First, lunar_day, overflow)
+                            },
+                            Err(error) => Err(error),
+                        }
+                    }
+                },
+                LunarOverflowPolicy::Error => Err(LunisolarDateError::MonthIncorrect),
+            };
+        },
+    };
+
+    let day = lunar_day.to_u8();
+
+    if day <= days {
+        return Ok(unsafe {
+            LunisolarDate::from_lunisolar_year_lunar_month_day_unsafe(
+                lunisolar_year,
+                lunar_month,
+                lunar_day,
+            )
+        });
+    }
+
+    match overflow {
+        LunarOverflowPolicy::Clamp => Ok(unsafe {
+            LunisolarDate::from_lunisolar_year_lunar_month_day_unsafe(
+                lunisolar_year,
+                lunar_month,
+                LunarDay::from_u8_unsafe(days),
+            )
+        }),
+        LunarOverflowPolicy::RollOver => {
+            let last_day = unsafe {
+                LunisolarDate::from_lunisolar_year_lunar_month_day_unsafe(
+                    lunisolar_year,
+                    lunar_month,
+                    LunarDay::from_u8_unsafe(days),
+                )
+            };
+
+            match last_day.checked_add_days((day - days) as u32) {
+                Ok(lunisolar_date) => Ok(lunisolar_date),
+                Err(_) => Err(LunisolarDateError::OutOfRange),
+            }
+        },
+        LunarOverflowPolicy::Error => Err(LunisolarDateError::DayIncorrect),
+    }
+}
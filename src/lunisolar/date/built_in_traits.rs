@@ -1,6 +1,9 @@
-use core::str::FromStr;
+use core::{
+    ops::{Add, AddAssign, Sub, SubAssign},
+    str::FromStr,
+};
 
-use chrono::prelude::*;
+use chrono::{Days, prelude::*};
 
 use super::{LunisolarDate, LunisolarDateError, LunisolarOutOfRangeError};
 use crate::{LunarDay, LunarMonth, LunarYear, LunisolarYear, SolarDate, SolarYear};
@@ -80,3 +83,93 @@ impl From<LunisolarDate> for LunarDay {
         value.to_lunar_day()
     }
 }
+
+impl Add<u32> for LunisolarDate {
+    type Output = LunisolarDate;
+
+    /// 計算往後 `rhs` 天的農曆日期。如果超出支援的範圍會 panic。
+    #[inline]
+    fn add(self, rhs: u32) -> Self::Output {
+        self.checked_add_days(rhs).expect("the lunisolar date is out of range")
+    }
+}
+
+impl AddAssign<u32> for LunisolarDate {
+    #[inline]
+    fn add_assign(&mut self, rhs: u32) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<u32> for LunisolarDate {
+    type Output = LunisolarDate;
+
+    /// 計算往前 `rhs` 天的農曆日期。如果超出支援的範圍會 panic。
+    #[inline]
+    fn sub(self, rhs: u32) -> Self::Output {
+        self.checked_sub_days(rhs).expect("the lunisolar date is out of range")
+    }
+}
+
+impl Sub<LunisolarDate> for LunisolarDate {
+    type Output = i32;
+
+    /// 計算兩個農曆日期相差幾天。
+    #[inline]
+    fn sub(self, rhs: LunisolarDate) -> Self::Output {
+        self.signed_days_since(rhs)
+    }
+}
+
+impl SubAssign<u32> for LunisolarDate {
+    #[inline]
+    fn sub_assign(&mut self, rhs: u32) {
+        *self = *self - rhs;
+    }
+}
+
+/// 取得 `Days` 的天數。`Days` 的欄位不公開，所以從 `NaiveDate::MIN` 往後推算；超過 `u32` 範圍時回傳 `None`。
+#[inline]
+fn days_to_u32(days: Days) -> Option<u32> {
+    let date = NaiveDate::MIN.checked_add_days(days)?;
+
+    u32::try_from(date.num_days_from_ce() - NaiveDate::MIN.num_days_from_ce()).ok()
+}
+
+impl Add<Days> for LunisolarDate {
+    type Output = LunisolarDate;
+
+    /// 計算往後 `rhs` 天的農曆日期。如果超出支援的範圍會 panic。
+    #[inline]
+    fn add(self, rhs: Days) -> Self::Output {
+        days_to_u32(rhs)
+            .and_then(|days| self.checked_add_days(days).ok())
+            .expect("the lunisolar date is out of range")
+    }
+}
+
+impl AddAssign<Days> for LunisolarDate {
+    #[inline]
+    fn add_assign(&mut self, rhs: Days) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Days> for LunisolarDate {
+    type Output = LunisolarDate;
+
+    /// 計算往前 `rhs` 天的農曆日期。如果超出支援的範圍會 panic。
+    #[inline]
+    fn sub(self, rhs: Days) -> Self::Output {
+        days_to_u32(rhs)
+            .and_then(|days| self.checked_sub_days(days).ok())
+            .expect("the lunisolar date is out of range")
+    }
+}
+
+impl SubAssign<Days> for LunisolarDate {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Days) {
+        *self = *self - rhs;
+    }
+}
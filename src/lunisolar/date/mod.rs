@@ -1,20 +1,24 @@
+mod arithmetic;
 mod built_in_traits;
 
 #[cfg(feature = "ba-zi-weight")]
 mod ba_zi_weight;
 mod parse;
+mod pillar;
 
 use core::{
     cmp::Ordering,
     fmt::{self, Display, Formatter, Write},
 };
 
+pub use arithmetic::*;
 use chrono::prelude::*;
+pub use pillar::*;
 
 use super::{LunisolarDateError, LunisolarOutOfRangeError, LunisolarYear, NEW_YEAR_DIFFERENCE};
 use crate::{
-    LunarDay, LunarMonth, LunarYear, MIN_YEAR_IN_SOLAR_CALENDAR, SolarDate, SolarDay, SolarMonth,
-    SolarYear,
+    LunarDay, LunarMonth, LunarYear, MAX_YEAR_IN_SOLAR_CALENDAR, MIN_YEAR_IN_SOLAR_CALENDAR,
+    Sexagenary, SolarDate, SolarDay, SolarMonth, SolarYear,
 };
 
 /// 最小支援的農曆日期(以西曆日期表示)：1901-02-19。
@@ -59,11 +63,10 @@ impl PartialOrd for LunisolarDate {
 impl Ord for LunisolarDate {
     #[inline]
     fn cmp(&self, other: &LunisolarDate) -> Ordering {
-        if self.lunisolar_year > other.lunisolar_year || self.lunar_month > other.lunar_month {
-            Ordering::Greater
-        } else {
-            self.lunar_day.cmp(&other.lunar_day)
-        }
+        self.lunisolar_year
+            .cmp(&other.lunisolar_year)
+            .then_with(|| self.lunar_month.cmp(&other.lunar_month))
+            .then_with(|| self.lunar_day.cmp(&other.lunar_day))
     }
 }
 
@@ -317,7 +320,7 @@ impl LunisolarDate {
 
         let solar_year = if n + NEW_YEAR_DIFFERENCE[(year - MIN_YEAR_IN_SOLAR_CALENDAR) as usize]
             as u16
-            >= solar_year.get_total_days()
+            > solar_year.get_total_days()
         {
             SolarYear::from_u16(year + 1)
         } else {
@@ -608,6 +611,66 @@ impl LunisolarDate {
     pub const fn to_lunar_day(self) -> LunarDay {
         self.lunar_day
     }
+
+    /// 取得星期幾。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{LunisolarDate, chrono::Weekday};
+    ///
+    /// let lunisolar_date = LunisolarDate::from_ymd(2024, 1, false, 1).unwrap();
+    ///
+    /// assert_eq!(Weekday::Sat, lunisolar_date.weekday());
+    /// ```
+    #[inline]
+    pub const fn weekday(self) -> Weekday {
+        self.to_solar_date().weekday()
+    }
+
+    /// 取得日柱，也就是此日的干支。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::LunisolarDate;
+    ///
+    /// let lunisolar_date = LunisolarDate::from_ymd(2024, 1, false, 1).unwrap();
+    ///
+    /// assert_eq!("甲辰", lunisolar_date.day_stem_branch().to_str());
+    /// ```
+    #[inline]
+    pub const fn day_stem_branch(self) -> Sexagenary {
+        self.to_solar_date().day_stem_branch()
+    }
+
+    /// 取得此日某個時間的時柱。23:00 到 24:00 的晚子時使用隔天的日干。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{LunisolarDate, chrono::NaiveTime};
+    ///
+    /// // 甲辰日
+    /// let lunisolar_date = LunisolarDate::from_ymd(2024, 1, false, 1).unwrap();
+    ///
+    /// assert_eq!(
+    ///     "甲子",
+    ///     lunisolar_date
+    ///         .hour_stem_branch(NaiveTime::from_hms_opt(0, 30, 0).unwrap())
+    ///         .to_str()
+    /// );
+    /// assert_eq!(
+    ///     "丙子",
+    ///     lunisolar_date
+    ///         .hour_stem_branch(NaiveTime::from_hms_opt(23, 30, 0).unwrap())
+    ///         .to_str()
+    /// );
+    /// ```
+    #[inline]
+    pub fn hour_stem_branch<T: Timelike>(self, time: T) -> Sexagenary {
+        self.to_solar_date().hour_stem_branch(time)
+    }
 }
 
 /// 農曆年月日相關計算方法。
@@ -678,6 +741,298 @@ impl LunisolarDate {
     pub const fn the_n_day_in_this_year(self) -> u16 {
         Self::the_n_day_in_this_year_inner(self.lunisolar_year, self.lunar_month, self.lunar_day)
     }
+
+    /// 計算此農曆日期與另一個農曆日期相差幾天。如果此農曆日期比較早，則回傳負數。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::LunisolarDate;
+    ///
+    /// let lunisolar_date_1 = LunisolarDate::from_ymd(2023, 1, false, 1).unwrap();
+    /// let lunisolar_date_2 = LunisolarDate::from_ymd(2024, 1, false, 1).unwrap();
+    ///
+    /// assert_eq!(384, lunisolar_date_2.signed_days_since(lunisolar_date_1));
+    /// assert_eq!(-384, lunisolar_date_1.signed_days_since(lunisolar_date_2));
+    /// ```
+    pub const fn signed_days_since(self, other: LunisolarDate) -> i32 {
+        let mut days = self.the_n_day_in_this_year() as i32 - other.the_n_day_in_this_year() as i32;
+
+        let year_s = self.lunisolar_year.to_u16();
+        let year_o = other.lunisolar_year.to_u16();
+
+        let (mut year, end, sign) =
+            if year_s > year_o { (year_o, year_s, 1) } else { (year_s, year_o, -1) };
+
+        while year < end {
+            let lunisolar_year =
+                unsafe { LunisolarYear::from_solar_year_unsafe(SolarYear::from_u16(year)) };
+
+            days += sign * lunisolar_year.get_total_days() as i32;
+
+            year += 1;
+        }
+
+        days
+    }
+
+    /// 利用農曆西曆年和該農曆年的第幾天來產生 `LunisolarDate` 實體。
+    ///
+    /// # Safety
+    /// 必須先確認 `n` 介於 `1` 到該農曆年的總天數之間。
+    pub(crate) const unsafe fn from_the_n_day_in_this_year_unsafe(
+        lunisolar_year: LunisolarYear,
+        mut n: u16,
+    ) -> LunisolarDate {
+        let leap_month = match lunisolar_year.get_leap_lunar_month() {
+            Some(leap_lunar_month) => leap_lunar_month.to_u8(),
+            None => 0,
+        };
+
+        let mut month = 1;
+
+        let mut is_leap = false;
+
+        loop {
+            let lunar_month = unsafe { LunarMonth::from_u8_with_leap_unsafe(month, is_leap) };
+
+            let month_days = match lunar_month.get_total_days(lunisolar_year) {
+                Some(days) => days as u16,
+                None => unreachable!(),
+            };
+
+            if n <= month_days {
+                return unsafe {
+                    Self::from_lunisolar_year_lunar_month_day_unsafe(
+                        lunisolar_year,
+                        lunar_month,
+                        LunarDay::from_u8_unsafe(n as u8),
+                    )
+                };
+            }
+
+            n -= month_days;
+
+            // 閏月緊接在同月份的一般月之後
+            if month == leap_month && !is_leap {
+                is_leap = true;
+            } else {
+                is_leap = false;
+                month += 1;
+            }
+        }
+    }
+
+    /// 計算往後 `days` 天的農曆日期。如果超出支援的範圍就回傳錯誤。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::LunisolarDate;
+    ///
+    /// let lunisolar_date = LunisolarDate::from_ymd(2023, 2, false, 30).unwrap();
+    ///
+    /// assert_eq!(
+    ///     LunisolarDate::from_ymd(2023, 2, true, 1).unwrap(),
+    ///     lunisolar_date.checked_add_days(1).unwrap()
+    /// );
+    /// assert_eq!(
+    ///     LunisolarDate::from_ymd(2024, 1, false, 1).unwrap(),
+    ///     lunisolar_date.checked_add_days(326).unwrap()
+    /// );
+    /// assert!(lunisolar_date.checked_add_days(100000).is_err());
+    /// ```
+    pub const fn checked_add_days(
+        self,
+        days: u32,
+    ) -> Result<LunisolarDate, LunisolarOutOfRangeError> {
+        let mut lunisolar_year = self.lunisolar_year;
+
+        // 從該農曆年第一天起算的天數差距
+        let mut n = self.the_n_day_in_this_year() as u64 - 1 + days as u64;
+
+        loop {
+            let year_days = lunisolar_year.get_total_days() as u64;
+
+            if n < year_days {
+                break;
+            }
+
+            n -= year_days;
+
+            let year = lunisolar_year.to_u16();
+
+            if year == MAX_YEAR_IN_SOLAR_CALENDAR {
+                return Err(LunisolarOutOfRangeError);
+            }
+
+            lunisolar_year =
+                unsafe { LunisolarYear::from_solar_year_unsafe(SolarYear::from_u16(year + 1)) };
+        }
+
+        Ok(unsafe { Self::from_the_n_day_in_this_year_unsafe(lunisolar_year, n as u16 + 1) })
+    }
+
+    /// 計算往前 `days` 天的農曆日期。如果超出支援的範圍就回傳錯誤。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::LunisolarDate;
+    ///
+    /// let lunisolar_date = LunisolarDate::from_ymd(2024, 1, false, 1).unwrap();
+    ///
+    /// assert_eq!(
+    ///     LunisolarDate::from_ymd(2023, 12, false, 30).unwrap(),
+    ///     lunisolar_date.checked_sub_days(1).unwrap()
+    /// );
+    /// assert!(lunisolar_date.checked_sub_days(100000).is_err());
+    /// ```
+    pub const fn checked_sub_days(
+        self,
+        days: u32,
+    ) -> Result<LunisolarDate, LunisolarOutOfRangeError> {
+        let mut lunisolar_year = self.lunisolar_year;
+
+        // 從該農曆年第一天起算的天數差距
+        let mut n = self.the_n_day_in_this_year() as u32 - 1;
+
+        let mut days = days;
+
+        while days > n {
+            days -= n + 1;
+
+            let year = lunisolar_year.to_u16();
+
+            if year == MIN_YEAR_IN_SOLAR_CALENDAR {
+                return Err(LunisolarOutOfRangeError);
+            }
+
+            lunisolar_year =
+                unsafe { LunisolarYear::from_solar_year_unsafe(SolarYear::from_u16(year - 1)) };
+
+            n = lunisolar_year.get_total_days() as u32 - 1;
+        }
+
+        n -= days;
+
+        Ok(unsafe { Self::from_the_n_day_in_this_year_unsafe(lunisolar_year, n as u16 + 1) })
+    }
+
+    /// 取得下一天的農曆日期。如果超出支援的範圍就回傳錯誤。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::LunisolarDate;
+    ///
+    /// let lunisolar_date = LunisolarDate::from_ymd(2023, 12, false, 30).unwrap();
+    ///
+    /// assert_eq!(
+    ///     LunisolarDate::from_ymd(2024, 1, false, 1).unwrap(),
+    ///     lunisolar_date.succ().unwrap()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn succ(self) -> Result<LunisolarDate, LunisolarOutOfRangeError> {
+        self.checked_add_days(1)
+    }
+
+    /// 取得前一天的農曆日期。如果超出支援的範圍就回傳錯誤。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::LunisolarDate;
+    ///
+    /// let lunisolar_date = LunisolarDate::from_ymd(2024, 1, false, 1).unwrap();
+    ///
+    /// assert_eq!(
+    ///     LunisolarDate::from_ymd(2023, 12, false, 30).unwrap(),
+    ///     lunisolar_date.pred().unwrap()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn pred(self) -> Result<LunisolarDate, LunisolarOutOfRangeError> {
+        self.checked_sub_days(1)
+    }
+}
+
+/// 農曆日期與連續日數(儒略日數、Rata Die)的轉換方法。
+impl LunisolarDate {
+    /// 透過 Rata Die(以西元 1 年 1 月 1 日為第 1 日的連續日數)來產生 `LunisolarDate` 實體。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::LunisolarDate;
+    ///
+    /// assert_eq!(
+    ///     LunisolarDate::from_ymd(2024, 1, false, 1).unwrap(),
+    ///     LunisolarDate::from_rata_die(738926).unwrap()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn from_rata_die(rata_die: i32) -> Result<LunisolarDate, LunisolarOutOfRangeError> {
+        match SolarDate::from_rata_die(rata_die) {
+            Ok(solar_date) => LunisolarDate::from_solar_date(solar_date),
+            Err(_) => Err(LunisolarOutOfRangeError),
+        }
+    }
+
+    /// 透過儒略日數(Julian Day Number)來產生 `LunisolarDate` 實體。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::LunisolarDate;
+    ///
+    /// assert_eq!(
+    ///     LunisolarDate::from_ymd(1999, 11, false, 25).unwrap(),
+    ///     LunisolarDate::from_julian_day_number(2451545).unwrap()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn from_julian_day_number(
+        julian_day_number: i32,
+    ) -> Result<LunisolarDate, LunisolarOutOfRangeError> {
+        match SolarDate::from_julian_day_number(julian_day_number) {
+            Ok(solar_date) => LunisolarDate::from_solar_date(solar_date),
+            Err(_) => Err(LunisolarOutOfRangeError),
+        }
+    }
+
+    /// 轉成 Rata Die(以西元 1 年 1 月 1 日為第 1 日的連續日數)。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::LunisolarDate;
+    ///
+    /// let lunisolar_date = LunisolarDate::from_ymd(2024, 1, false, 1).unwrap();
+    ///
+    /// assert_eq!(738926, lunisolar_date.to_rata_die());
+    /// ```
+    #[inline]
+    pub const fn to_rata_die(self) -> i32 {
+        self.to_solar_date().to_rata_die()
+    }
+
+    /// 轉成儒略日數(Julian Day Number)。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::LunisolarDate;
+    ///
+    /// let lunisolar_date = LunisolarDate::from_ymd(1999, 11, false, 25).unwrap();
+    ///
+    /// assert_eq!(2451545, lunisolar_date.to_julian_day_number());
+    /// ```
+    #[inline]
+    pub const fn to_julian_day_number(self) -> i32 {
+        self.to_solar_date().to_julian_day_number()
+    }
 }
 
 /// 額外的實作。
@@ -0,0 +1,207 @@
+use chrono::prelude::*;
+
+use super::{LunisolarDate, LunisolarOutOfRangeError, LunisolarYear};
+use crate::{Sexagenary, SolarDate, SolarYear};
+
+/// 推算月柱時，劃分月份的方式。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MonthPillarMode {
+    /// 直接以農曆月份來推算(正月為寅月)，閏月視為前一個月(例如閏二月視為二月)。
+    #[default]
+    LunarMonth,
+    /// 以「節」(立春、驚蟄、...、小寒)的交節時刻為月份的分界，年份也以立春為分界，也就是八字命理使用的方式。交節時刻以東八區(UTC+8)為準，精確到分鐘。
+    SolarTerm,
+}
+
+/// 年柱和月柱相關的計算方法。
+impl LunisolarDate {
+    /// 取得此日某個時間的年柱，也就是所在年份的干支。使用 `MonthPillarMode::SolarTerm` 時，年份以立春的交節時刻為分界；使用 `MonthPillarMode::LunarMonth` 時，`time` 不影響結果。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{
+    ///     LunisolarDate, MonthPillarMode, chrono::NaiveTime,
+    /// };
+    ///
+    /// let lunisolar_date = LunisolarDate::from_ymd(2023, 12, false, 25).unwrap();
+    ///
+    /// // 2024-02-04 16:27 立春
+    /// let before = NaiveTime::from_hms_opt(16, 26, 0).unwrap();
+    /// let after = NaiveTime::from_hms_opt(16, 27, 0).unwrap();
+    ///
+    /// assert_eq!(
+    ///     "癸卯",
+    ///     lunisolar_date
+    ///         .year_stem_branch(after, MonthPillarMode::LunarMonth)
+    ///         .to_str()
+    /// );
+    /// assert_eq!(
+    ///     "癸卯",
+    ///     lunisolar_date
+    ///         .year_stem_branch(before, MonthPillarMode::SolarTerm)
+    ///         .to_str()
+    /// );
+    /// assert_eq!(
+    ///     "甲辰",
+    ///     lunisolar_date
+    ///         .year_stem_branch(after, MonthPillarMode::SolarTerm)
+    ///         .to_str()
+    /// );
+    /// ```
+    #[inline]
+    pub fn year_stem_branch<T: Timelike>(self, time: T, mode: MonthPillarMode) -> Sexagenary {
+        match mode {
+            MonthPillarMode::LunarMonth => self.lunisolar_year.to_lunar_year().to_sexagenary(),
+            MonthPillarMode::SolarTerm => match self.to_solar_date().year_stem_branch(time) {
+                Ok(sexagenary) => sexagenary,
+                Err(_) => unreachable!(),
+            },
+        }
+    }
+
+    /// 利用五虎遁，透過年干來取得此日某個時間的月柱，也就是所在月份的干支。使用 `MonthPillarMode::LunarMonth` 時，`time` 不影響結果。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{
+    ///     LunisolarDate, MonthPillarMode, chrono::NaiveTime,
+    /// };
+    ///
+    /// let lunisolar_date = LunisolarDate::from_ymd(2023, 12, false, 25).unwrap();
+    ///
+    /// // 2024-02-04 16:27 立春
+    /// let before = NaiveTime::from_hms_opt(16, 26, 0).unwrap();
+    /// let after = NaiveTime::from_hms_opt(16, 27, 0).unwrap();
+    ///
+    /// assert_eq!(
+    ///     "乙丑",
+    ///     lunisolar_date
+    ///         .month_stem_branch(after, MonthPillarMode::LunarMonth)
+    ///         .to_str()
+    /// );
+    /// assert_eq!(
+    ///     "乙丑",
+    ///     lunisolar_date
+    ///         .month_stem_branch(before, MonthPillarMode::SolarTerm)
+    ///         .to_str()
+    /// );
+    /// assert_eq!(
+    ///     "丙寅",
+    ///     lunisolar_date
+    ///         .month_stem_branch(after, MonthPillarMode::SolarTerm)
+    ///         .to_str()
+    /// );
+    /// ```
+    #[inline]
+    pub fn month_stem_branch<T: Timelike>(self, time: T, mode: MonthPillarMode) -> Sexagenary {
+        match mode {
+            MonthPillarMode::LunarMonth => unsafe {
+                Sexagenary::from_year_stem_and_month_unsafe(
+                    self.lunisolar_year.to_heavenly_stems(),
+                    self.lunar_month.to_u8(),
+                )
+            },
+            MonthPillarMode::SolarTerm => match self.to_solar_date().month_stem_branch(time) {
+                Ok(sexagenary) => sexagenary,
+                Err(_) => unreachable!(),
+            },
+        }
+    }
+}
+
+/// 年柱和月柱相關的計算方法。
+impl SolarDate {
+    /// 以立春的交節時刻為年份的分界，取得此日某個時間的年柱，也就是所在年份的干支。交節時刻以東八區(UTC+8)為準，精確到分鐘。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{SolarDate, chrono::NaiveTime};
+    ///
+    /// let solar_date = SolarDate::from_ymd(2024, 2, 4).unwrap();
+    ///
+    /// // 2024-02-04 16:27 立春
+    /// assert_eq!(
+    ///     "癸卯",
+    ///     solar_date
+    ///         .year_stem_branch(NaiveTime::from_hms_opt(16, 26, 0).unwrap())
+    ///         .unwrap()
+    ///         .to_str()
+    /// );
+    /// assert_eq!(
+    ///     "甲辰",
+    ///     solar_date
+    ///         .year_stem_branch(NaiveTime::from_hms_opt(16, 27, 0).unwrap())
+    ///         .unwrap()
+    ///         .to_str()
+    /// );
+    /// ```
+    #[inline]
+    pub fn year_stem_branch<T: Timelike>(
+        self,
+        time: T,
+    ) -> Result<Sexagenary, LunisolarOutOfRangeError> {
+        if !self.is_safe() {
+            return Err(LunisolarOutOfRangeError);
+        }
+
+        let (year, _) = unsafe { self.to_solar_term_month_unsafe(minutes_of_day(time)) };
+
+        let lunisolar_year =
+            unsafe { LunisolarYear::from_solar_year_unsafe(SolarYear::from_u16(year)) };
+
+        Ok(lunisolar_year.to_lunar_year().to_sexagenary())
+    }
+
+    /// 以「節」(立春、驚蟄、...、小寒)的交節時刻為月份的分界，利用五虎遁來取得此日某個時間的月柱，也就是所在月份的干支。交節時刻以東八區(UTC+8)為準，精確到分鐘。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{SolarDate, chrono::NaiveTime};
+    ///
+    /// let solar_date = SolarDate::from_ymd(2024, 2, 4).unwrap();
+    ///
+    /// // 2024-02-04 16:27 立春
+    /// assert_eq!(
+    ///     "乙丑",
+    ///     solar_date
+    ///         .month_stem_branch(NaiveTime::from_hms_opt(16, 26, 0).unwrap())
+    ///         .unwrap()
+    ///         .to_str()
+    /// );
+    /// assert_eq!(
+    ///     "丙寅",
+    ///     solar_date
+    ///         .month_stem_branch(NaiveTime::from_hms_opt(16, 27, 0).unwrap())
+    ///         .unwrap()
+    ///         .to_str()
+    /// );
+    /// ```
+    #[inline]
+    pub fn month_stem_branch<T: Timelike>(
+        self,
+        time: T,
+    ) -> Result<Sexagenary, LunisolarOutOfRangeError> {
+        if !self.is_safe() {
+            return Err(LunisolarOutOfRangeError);
+        }
+
+        let (year, month) = unsafe { self.to_solar_term_month_unsafe(minutes_of_day(time)) };
+
+        let lunisolar_year =
+            unsafe { LunisolarYear::from_solar_year_unsafe(SolarYear::from_u16(year)) };
+
+        Ok(unsafe {
+            Sexagenary::from_year_stem_and_month_unsafe(lunisolar_year.to_heavenly_stems(), month)
+        })
+    }
+}
+
+/// 計算某個時間從 00:00 起算經過的分鐘數。
+#[inline]
+fn minutes_of_day<T: Timelike>(time: T) -> u16 {
+    (time.hour() * 60 + time.minute()) as u16
+}
@@ -0,0 +1,223 @@
+use core::iter::FusedIterator;
+
+use super::{LunisolarDate, LunisolarMonth, LunisolarYear};
+
+/// 依序走訪某個農曆年中每個農曆月(含閏月)的迭代器。可以透過 `LunisolarYear::months` 方法來取得。
+#[derive(Debug, Clone)]
+pub struct LunisolarMonths {
+    lunisolar_year: LunisolarYear,
+    front:          u8,
+    back:           u8,
+}
+
+impl LunisolarMonths {
+    #[inline]
+    pub(crate) const fn new(lunisolar_year: LunisolarYear) -> Self {
+        Self {
+            lunisolar_year,
+            front: 0,
+            back: lunisolar_year.get_total_months(),
+        }
+    }
+
+    #[inline]
+    const fn get(&self, index: u8) -> LunisolarMonth {
+        unsafe {
+            LunisolarMonth::from_lunisolar_year_lunar_month_unsafe(
+                self.lunisolar_year,
+                self.lunisolar_year.get_lunar_month_by_index(index),
+            )
+        }
+    }
+}
+
+impl Iterator for LunisolarMonths {
+    type Item = LunisolarMonth;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            let lunisolar_month = self.get(self.front);
+
+            self.front += 1;
+
+            Some(lunisolar_month)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.back - self.front) as usize;
+
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for LunisolarMonths {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+
+            Some(self.get(self.back))
+        } else {
+            None
+        }
+    }
+}
+
+impl ExactSizeIterator for LunisolarMonths {}
+
+impl FusedIterator for LunisolarMonths {}
+
+/// 依序走訪某個農曆年或農曆月中每一天的迭代器。可以透過 `LunisolarYear::days` 或 `LunisolarMonth::days` 方法來取得。
+#[derive(Debug, Clone)]
+pub struct LunisolarDays {
+    lunisolar_year: LunisolarYear,
+    front:          u16,
+    back:           u16,
+}
+
+impl LunisolarDays {
+    /// `front` 和 `back` 為該農曆年的第幾天(`back` 不包含在內)。
+    #[inline]
+    pub(crate) const fn new(lunisolar_year: LunisolarYear, front: u16, back: u16) -> Self {
+        Self {
+            lunisolar_year,
+            front,
+            back,
+        }
+    }
+}
+
+impl Iterator for LunisolarDays {
+    type Item = LunisolarDate;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            let lunisolar_date = unsafe {
+                LunisolarDate::from_the_n_day_in_this_year_unsafe(self.lunisolar_year, self.front)
+            };
+
+            self.front += 1;
+
+            Some(lunisolar_date)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.back - self.front) as usize;
+
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for LunisolarDays {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+
+            Some(unsafe {
+                LunisolarDate::from_the_n_day_in_this_year_unsafe(self.lunisolar_year, self.back)
+            })
+        } else {
+            None
+        }
+    }
+}
+
+impl ExactSizeIterator for LunisolarDays {}
+
+impl FusedIterator for LunisolarDays {}
+
+/// 額外的實作。
+impl LunisolarYear {
+    /// 依序走訪此農曆年中的每個農曆月，閏月會緊接在同數字的一般月份之後。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{LunarMonth, LunisolarYear, SolarYear};
+    ///
+    /// let lunisolar_year =
+    ///     LunisolarYear::from_solar_year(SolarYear::from_u16(2023)).unwrap();
+    ///
+    /// let mut months = lunisolar_year.months();
+    ///
+    /// assert_eq!(13, months.len());
+    ///
+    /// let lunisolar_month = months.nth(2).unwrap();
+    ///
+    /// assert_eq!(LunarMonth::LeapSecond, lunisolar_month.to_lunar_month());
+    /// assert_eq!(29, lunisolar_month.total_days());
+    /// ```
+    #[inline]
+    pub const fn months(self) -> LunisolarMonths {
+        LunisolarMonths::new(self)
+    }
+
+    /// 依序走訪此農曆年中的每一天。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{LunisolarDate, LunisolarYear, SolarYear};
+    ///
+    /// let lunisolar_year =
+    ///     LunisolarYear::from_solar_year(SolarYear::from_u16(2023)).unwrap();
+    ///
+    /// let mut days = lunisolar_year.days();
+    ///
+    /// assert_eq!(384, days.len());
+    /// assert_eq!(
+    ///     LunisolarDate::from_ymd(2023, 1, false, 1).unwrap(),
+    ///     days.next().unwrap()
+    /// );
+    /// assert_eq!(
+    ///     LunisolarDate::from_ymd(2023, 12, false, 30).unwrap(),
+    ///     days.next_back().unwrap()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn days(self) -> LunisolarDays {
+        LunisolarDays::new(self, 1, self.get_total_days() + 1)
+    }
+}
+
+/// 額外的實作。
+impl LunisolarMonth {
+    /// 依序走訪此農曆月中的每一天。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{LunisolarDate, LunisolarMonth};
+    ///
+    /// let lunisolar_month = LunisolarMonth::from_ym(2023, 2, true).unwrap();
+    ///
+    /// let mut days = lunisolar_month.days();
+    ///
+    /// assert_eq!(29, days.len());
+    /// assert_eq!(
+    ///     LunisolarDate::from_ymd(2023, 2, true, 1).unwrap(),
+    ///     days.next().unwrap()
+    /// );
+    /// assert_eq!(
+    ///     LunisolarDate::from_ymd(2023, 2, true, 29).unwrap(),
+    ///     days.next_back().unwrap()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn days(self) -> LunisolarDays {
+        let front = self.first_day().the_n_day_in_this_year();
+
+        LunisolarDays::new(self.to_lunisolar_year(), front, front + self.total_days() as u16)
+    }
+}
@@ -1,9 +1,15 @@
 mod constants;
 mod date;
 mod errors;
+mod iter;
+mod month;
+mod range;
 mod year;
 
 use constants::*;
 pub use date::*;
 pub use errors::*;
+pub use iter::*;
+pub use month::*;
+pub use range::*;
 pub use year::*;
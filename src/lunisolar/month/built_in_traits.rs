@@ -0,0 +1,41 @@
+use core::str::FromStr;
+
+use super::{LunisolarDate, LunisolarDateError, LunisolarMonth};
+use crate::{LunarMonth, LunarYear, LunisolarYear};
+
+impl FromStr for LunisolarMonth {
+    type Err = LunisolarDateError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        LunisolarMonth::parse_str(s)
+    }
+}
+
+impl From<LunisolarDate> for LunisolarMonth {
+    #[inline]
+    fn from(value: LunisolarDate) -> Self {
+        value.to_lunisolar_month()
+    }
+}
+
+impl From<LunisolarMonth> for LunisolarYear {
+    #[inline]
+    fn from(value: LunisolarMonth) -> Self {
+        value.to_lunisolar_year()
+    }
+}
+
+impl From<LunisolarMonth> for LunarYear {
+    #[inline]
+    fn from(value: LunisolarMonth) -> Self {
+        value.to_lunar_year()
+    }
+}
+
+impl From<LunisolarMonth> for LunarMonth {
+    #[inline]
+    fn from(value: LunisolarMonth) -> Self {
+        value.to_lunar_month()
+    }
+}
@@ -0,0 +1,422 @@
+mod built_in_traits;
+mod parse;
+
+use core::fmt::{self, Display, Formatter, Write};
+
+use super::{LunisolarDate, LunisolarDateError, LunisolarOutOfRangeError, LunisolarYear};
+use crate::{
+    LunarDay, LunarMonth, LunarYear, MAX_YEAR_IN_SOLAR_CALENDAR, MIN_YEAR_IN_SOLAR_CALENDAR,
+    SolarDate, SolarYear,
+};
+
+/// 農曆年月，某個農曆西曆年中的某個農曆月(含閏月)。
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub struct LunisolarMonth {
+    lunisolar_year: LunisolarYear,
+    lunar_month:    LunarMonth,
+}
+
+impl Display for LunisolarMonth {
+    /// Formats the value using the given formatter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::LunisolarMonth;
+    ///
+    /// let lunisolar_month = LunisolarMonth::from_ym(2023, 2, true).unwrap();
+    ///
+    /// assert_eq!("二〇二三　癸卯、兔年　閏二月", format!("{lunisolar_month}"));
+    /// assert_eq!("二〇二三　癸卯、兔年　闰二月", format!("{lunisolar_month:#}"));
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.lunisolar_year, f)?;
+        f.write_char('　')?;
+        Display::fmt(&self.to_lunar_year(), f)?;
+        f.write_char('、')?;
+        Display::fmt(&self.lunisolar_year.to_zodiac(), f)?;
+        f.write_char('年')?;
+        f.write_char('　')?;
+        Display::fmt(&self.lunar_month, f)
+    }
+}
+
+/// 用以建立 `LunisolarMonth` 結構實體的關聯函數。
+impl LunisolarMonth {
+    /// 利用農曆西曆年和農曆月來產生 `LunisolarMonth` 實體。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{
+    ///     LunarMonth, LunisolarMonth, LunisolarYear, SolarYear,
+    /// };
+    ///
+    /// let lunisolar_month = unsafe {
+    ///     LunisolarMonth::from_lunisolar_year_lunar_month_unsafe(
+    ///         LunisolarYear::from_solar_year(SolarYear::from_u16(2023)).unwrap(),
+    ///         LunarMonth::LeapSecond,
+    ///     )
+    /// };
+    /// ```
+    ///
+    /// # Safety
+    /// 請先確保傳入的農曆月在該農曆西曆年中是存在的。
+    #[inline]
+    pub const unsafe fn from_lunisolar_year_lunar_month_unsafe(
+        lunisolar_year: LunisolarYear,
+        lunar_month: LunarMonth,
+    ) -> LunisolarMonth {
+        LunisolarMonth {
+            lunisolar_year,
+            lunar_month,
+        }
+    }
+
+    /// 利用農曆西曆年和農曆月來產生 `LunisolarMonth` 實體。如果傳入的是閏月，但是該年沒有該閏月的話就回傳錯誤。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{
+    ///     LunarMonth, LunisolarMonth, LunisolarYear, SolarYear,
+    /// };
+    ///
+    /// let lunisolar_year =
+    ///     LunisolarYear::from_solar_year(SolarYear::from_u16(2023)).unwrap();
+    ///
+    /// assert!(
+    ///     LunisolarMonth::from_lunisolar_year_lunar_month(
+    ///         lunisolar_year,
+    ///         LunarMonth::LeapSecond
+    ///     )
+    ///     .is_ok()
+    /// );
+    /// assert!(
+    ///     LunisolarMonth::from_lunisolar_year_lunar_month(
+    ///         lunisolar_year,
+    ///         LunarMonth::LeapThird
+    ///     )
+    ///     .is_err()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn from_lunisolar_year_lunar_month(
+        lunisolar_year: LunisolarYear,
+        lunar_month: LunarMonth,
+    ) -> Result<LunisolarMonth, LunisolarDateError> {
+        match lunar_month.get_total_days(lunisolar_year) {
+            Some(_) => Ok(LunisolarMonth {
+                lunisolar_year,
+                lunar_month,
+            }),
+            None => Err(LunisolarDateError::MonthIncorrect),
+        }
+    }
+
+    /// 利用**農曆西曆年**和**農曆月**來產生 `LunisolarMonth` 實體。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chinese_lunisolar_calendar::LunisolarMonth;
+    ///
+    /// let lunisolar_month = LunisolarMonth::from_ym(2023, 2, true).unwrap();
+    /// ```
+    #[inline]
+    pub const fn from_ym(
+        year: u16,
+        month: u8,
+        leap: bool,
+    ) -> Result<LunisolarMonth, LunisolarDateError> {
+        let lunisolar_year = match LunisolarYear::from_solar_year(SolarYear::from_u16(year)) {
+            Ok(lunisolar_year) => lunisolar_year,
+            Err(_) => return Err(LunisolarDateError::OutOfRange),
+        };
+
+        let lunar_month = match LunarMonth::from_u8_with_leap(month, leap) {
+            Ok(lunar_month) => lunar_month,
+            Err(_) => return Err(LunisolarDateError::MonthIncorrect),
+        };
+
+        Self::from_lunisolar_year_lunar_month(lunisolar_year, lunar_month)
+    }
+}
+
+/// 將 `LunisolarMonth` 結構實體轉成其它型別的方法。
+impl LunisolarMonth {
+    /// 取得農曆西曆年。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{
+    ///     LunisolarMonth, LunisolarYear, SolarYear,
+    /// };
+    ///
+    /// let lunisolar_month = LunisolarMonth::from_ym(2023, 2, true).unwrap();
+    ///
+    /// assert_eq!(
+    ///     LunisolarYear::from_solar_year(SolarYear::from_u16(2023)).unwrap(),
+    ///     lunisolar_month.to_lunisolar_year()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn to_lunisolar_year(self) -> LunisolarYear {
+        self.lunisolar_year
+    }
+
+    /// 取得農曆年。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{LunarYear, LunisolarMonth};
+    ///
+    /// let lunisolar_month = LunisolarMonth::from_ym(2023, 2, true).unwrap();
+    ///
+    /// assert_eq!(
+    ///     LunarYear::parse_str("癸卯").unwrap(),
+    ///     lunisolar_month.to_lunar_year()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn to_lunar_year(self) -> LunarYear {
+        self.lunisolar_year.to_lunar_year()
+    }
+
+    /// 取得農曆月。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{LunarMonth, LunisolarMonth};
+    ///
+    /// let lunisolar_month = LunisolarMonth::from_ym(2023, 2, true).unwrap();
+    ///
+    /// assert_eq!(LunarMonth::LeapSecond, lunisolar_month.to_lunar_month());
+    /// ```
+    #[inline]
+    pub const fn to_lunar_month(self) -> LunarMonth {
+        self.lunar_month
+    }
+}
+
+/// 農曆年月相關計算方法。
+impl LunisolarMonth {
+    /// 計算此農曆月共有幾天。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::LunisolarMonth;
+    ///
+    /// assert_eq!(
+    ///     30,
+    ///     LunisolarMonth::from_ym(2023, 2, false).unwrap().total_days()
+    /// );
+    /// assert_eq!(
+    ///     29,
+    ///     LunisolarMonth::from_ym(2023, 2, true).unwrap().total_days()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn total_days(self) -> u8 {
+        match self.lunar_month.get_total_days(self.lunisolar_year) {
+            Some(days) => days,
+            None => unreachable!(),
+        }
+    }
+
+    /// 取得此農曆月的第一天(初一)。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{LunisolarDate, LunisolarMonth};
+    ///
+    /// let lunisolar_month = LunisolarMonth::from_ym(2023, 2, true).unwrap();
+    ///
+    /// assert_eq!(
+    ///     LunisolarDate::from_ymd(2023, 2, true, 1).unwrap(),
+    ///     lunisolar_month.first_day()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn first_day(self) -> LunisolarDate {
+        unsafe {
+            LunisolarDate::from_lunisolar_year_lunar_month_day_unsafe(
+                self.lunisolar_year,
+                self.lunar_month,
+                LunarDay::First,
+            )
+        }
+    }
+
+    /// 取得此農曆月的最後一天(廿九或三十)。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{LunisolarDate, LunisolarMonth};
+    ///
+    /// let lunisolar_month = LunisolarMonth::from_ym(2023, 2, true).unwrap();
+    ///
+    /// assert_eq!(
+    ///     LunisolarDate::from_ymd(2023, 2, true, 29).unwrap(),
+    ///     lunisolar_month.last_day()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn last_day(self) -> LunisolarDate {
+        unsafe {
+            LunisolarDate::from_lunisolar_year_lunar_month_day_unsafe(
+                self.lunisolar_year,
+                self.lunar_month,
+                LunarDay::from_u8_unsafe(self.total_days()),
+            )
+        }
+    }
+
+    /// 取得此農曆月的第一天(初一)所對應的西曆日期。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{LunisolarMonth, SolarDate};
+    ///
+    /// let lunisolar_month = LunisolarMonth::from_ym(2023, 2, true).unwrap();
+    ///
+    /// assert_eq!(
+    ///     SolarDate::from_ymd(2023, 3, 22).unwrap(),
+    ///     lunisolar_month.first_day_in_solar_date()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn first_day_in_solar_date(self) -> SolarDate {
+        self.first_day().to_solar_date()
+    }
+
+    /// 取得此農曆月的最後一天(廿九或三十)所對應的西曆日期。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{LunisolarMonth, SolarDate};
+    ///
+    /// let lunisolar_month = LunisolarMonth::from_ym(2023, 2, true).unwrap();
+    ///
+    /// assert_eq!(
+    ///     SolarDate::from_ymd(2023, 4, 19).unwrap(),
+    ///     lunisolar_month.last_day_in_solar_date()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn last_day_in_solar_date(self) -> SolarDate {
+        self.last_day().to_solar_date()
+    }
+
+    /// 取得下一個農曆月(含閏月)。如果超出支援的範圍就回傳錯誤。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::LunisolarMonth;
+    ///
+    /// let lunisolar_month = LunisolarMonth::from_ym(2023, 2, false).unwrap();
+    ///
+    /// assert_eq!(
+    ///     LunisolarMonth::from_ym(2023, 2, true).unwrap(),
+    ///     lunisolar_month.next().unwrap()
+    /// );
+    /// ```
+    pub const fn next(self) -> Result<LunisolarMonth, LunisolarOutOfRangeError> {
+        let index = self.lunisolar_year.get_lunar_month_index(self.lunar_month) + 1;
+
+        if index < self.lunisolar_year.get_total_months() {
+            Ok(LunisolarMonth {
+                lunisolar_year: self.lunisolar_year,
+                lunar_month:    self.lunisolar_year.get_lunar_month_by_index(index),
+            })
+        } else {
+            let year = self.lunisolar_year.to_u16();
+
+            if year == MAX_YEAR_IN_SOLAR_CALENDAR {
+                return Err(LunisolarOutOfRangeError);
+            }
+
+            Ok(LunisolarMonth {
+                lunisolar_year: unsafe {
+                    LunisolarYear::from_solar_year_unsafe(SolarYear::from_u16(year + 1))
+                },
+                lunar_month:    LunarMonth::First,
+            })
+        }
+    }
+
+    /// 取得上一個農曆月(含閏月)。如果超出支援的範圍就回傳錯誤。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::LunisolarMonth;
+    ///
+    /// let lunisolar_month = LunisolarMonth::from_ym(2023, 3, false).unwrap();
+    ///
+    /// assert_eq!(
+    ///     LunisolarMonth::from_ym(2023, 2, true).unwrap(),
+    ///     lunisolar_month.prev().unwrap()
+    /// );
+    /// ```
+    pub const fn prev(self) -> Result<LunisolarMonth, LunisolarOutOfRangeError> {
+        let index = self.lunisolar_year.get_lunar_month_index(self.lunar_month);
+
+        if index > 0 {
+            Ok(LunisolarMonth {
+                lunisolar_year: self.lunisolar_year,
+                lunar_month:    self.lunisolar_year.get_lunar_month_by_index(index - 1),
+            })
+        } else {
+            let year = self.lunisolar_year.to_u16();
+
+            if year == MIN_YEAR_IN_SOLAR_CALENDAR {
+                return Err(LunisolarOutOfRangeError);
+            }
+
+            let lunisolar_year =
+                unsafe { LunisolarYear::from_solar_year_unsafe(SolarYear::from_u16(year - 1)) };
+
+            Ok(LunisolarMonth {
+                lunisolar_year,
+                lunar_month: lunisolar_year
+                    .get_lunar_month_by_index(lunisolar_year.get_total_months() - 1),
+            })
+        }
+    }
+}
+
+/// 額外的實作。
+impl LunisolarDate {
+    /// 取得農曆年月。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{LunisolarDate, LunisolarMonth};
+    ///
+    /// let lunisolar_date = LunisolarDate::from_ymd(2023, 2, true, 15).unwrap();
+    ///
+    /// assert_eq!(
+    ///     LunisolarMonth::from_ym(2023, 2, true).unwrap(),
+    ///     lunisolar_date.to_lunisolar_month()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn to_lunisolar_month(self) -> LunisolarMonth {
+        LunisolarMonth {
+            lunisolar_year: self.to_lunisolar_year(),
+            lunar_month:    self.to_lunar_month(),
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use super::{LunarMonth, LunisolarDateError, LunisolarMonth, LunisolarYear, SolarYear};
+
+/// 用以解析字串的關聯函數。
+impl LunisolarMonth {
+    /// 用中文農曆西曆年和農曆月字串來產生 `LunisolarMonth` 實體。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::LunisolarMonth;
+    ///
+    /// let lunisolar_month = LunisolarMonth::parse_str("二〇二三　癸卯、兔年　閏二月").unwrap();
+    /// let lunisolar_month = LunisolarMonth::parse_str("二零二三年闰二月").unwrap();
+    /// ```
+    pub fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, LunisolarDateError> {
+        let s = s.as_ref();
+
+        let year_index = {
+            match s.find('　') {
+                Some(index) => index,
+                None => match s.find('年') {
+                    Some(index) => index,
+                    None => return Err(LunisolarDateError::YearIncorrect),
+                },
+            }
+        };
+
+        let year_str = s[..year_index].trim();
+
+        let lunisolar_year = LunisolarYear::from_solar_year(
+            SolarYear::parse_str(year_str).map_err(|_| LunisolarDateError::YearIncorrect)?,
+        )?;
+
+        let month_str = s[year_index + 3..].trim();
+
+        let month_str = {
+            match month_str.find('年') {
+                Some(index) => month_str[index + 3..].trim(),
+                None => match month_str.find('　') {
+                    Some(index) => month_str[index + 3..].trim(),
+                    None => month_str,
+                },
+            }
+        };
+
+        let lunar_month = LunarMonth::parse_str(month_str)?;
+
+        Self::from_lunisolar_year_lunar_month(lunisolar_year, lunar_month)
+    }
+}
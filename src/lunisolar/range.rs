@@ -0,0 +1,338 @@
+use core::iter::FusedIterator;
+
+use super::{LunisolarDate, LunisolarMonth, LunisolarYear};
+use crate::SolarDateRange;
+
+/// 農曆日期範圍，包含起始日與結束日。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LunisolarDateRange {
+    start: LunisolarDate,
+    end:   LunisolarDate,
+}
+
+/// 用以建立 `LunisolarDateRange` 結構實體的關聯函數。
+impl LunisolarDateRange {
+    /// 利用起始日和結束日(包含)來產生 `LunisolarDateRange` 實體。如果起始日比結束日晚，就回傳 `None`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{LunisolarDate, LunisolarDateRange};
+    ///
+    /// let start = LunisolarDate::from_ymd(2024, 1, false, 1).unwrap();
+    /// let end = LunisolarDate::from_ymd(2024, 1, false, 15).unwrap();
+    ///
+    /// assert!(LunisolarDateRange::new(start, end).is_some());
+    /// assert!(LunisolarDateRange::new(end, start).is_none());
+    /// ```
+    #[inline]
+    pub const fn new(start: LunisolarDate, end: LunisolarDate) -> Option<Self> {
+        if start.to_solar_date().cmp(&end.to_solar_date()).is_le() {
+            Some(Self {
+                start,
+                end,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+/// 將 `LunisolarDateRange` 結構實體轉成其它型別的方法。
+impl LunisolarDateRange {
+    /// 取得起始日。
+    #[inline]
+    pub const fn start(self) -> LunisolarDate {
+        self.start
+    }
+
+    /// 取得結束日(包含)。
+    #[inline]
+    pub const fn end(self) -> LunisolarDate {
+        self.end
+    }
+
+    /// 轉成西曆日期範圍。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{LunisolarMonth, SolarDate};
+    ///
+    /// let solar_date_range = LunisolarMonth::from_ym(2024, 1, false)
+    ///     .unwrap()
+    ///     .range()
+    ///     .to_solar_date_range();
+    ///
+    /// assert_eq!(
+    ///     SolarDate::from_ymd(2024, 2, 10).unwrap(),
+    ///     solar_date_range.start()
+    /// );
+    /// assert_eq!(
+    ///     SolarDate::from_ymd(2024, 3, 9).unwrap(),
+    ///     solar_date_range.end()
+    /// );
+    /// ```
+    #[inline]
+    pub fn to_solar_date_range(self) -> SolarDateRange {
+        match SolarDateRange::new(self.start.to_solar_date(), self.end.to_solar_date()) {
+            Some(solar_date_range) => solar_date_range,
+            None => unreachable!(),
+        }
+    }
+
+    /// 走訪範圍內的每一天。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{LunisolarDate, LunisolarDateRange};
+    ///
+    /// let start = LunisolarDate::from_ymd(2023, 12, false, 29).unwrap();
+    /// let end = LunisolarDate::from_ymd(2024, 1, false, 1).unwrap();
+    ///
+    /// let range = LunisolarDateRange::new(start, end).unwrap();
+    ///
+    /// let mut iter = range.iter();
+    ///
+    /// assert_eq!(Some(start), iter.next());
+    /// assert_eq!(Some(end), iter.next_back());
+    /// assert_eq!(1, iter.len());
+    /// ```
+    #[inline]
+    pub fn iter(self) -> LunisolarDateRangeIter {
+        LunisolarDateRangeIter {
+            front:     self.start,
+            back:      self.end,
+            remaining: self.len_days(),
+        }
+    }
+}
+
+/// 農曆日期範圍相關計算方法。
+impl LunisolarDateRange {
+    /// 計算範圍內共有幾天。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{LunisolarDate, LunisolarDateRange};
+    ///
+    /// let start = LunisolarDate::from_ymd(2024, 1, false, 1).unwrap();
+    /// let end = LunisolarDate::from_ymd(2024, 1, false, 15).unwrap();
+    ///
+    /// assert_eq!(15, LunisolarDateRange::new(start, end).unwrap().len_days());
+    /// ```
+    #[inline]
+    pub const fn len_days(self) -> u32 {
+        self.end.signed_days_since(self.start) as u32 + 1
+    }
+
+    /// 判斷某個農曆日期是否在範圍內。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{LunisolarDate, LunisolarDateRange};
+    ///
+    /// let start = LunisolarDate::from_ymd(2024, 1, false, 1).unwrap();
+    /// let end = LunisolarDate::from_ymd(2024, 1, false, 15).unwrap();
+    ///
+    /// let range = LunisolarDateRange::new(start, end).unwrap();
+    ///
+    /// assert!(
+    ///     range.contains(LunisolarDate::from_ymd(2024, 1, false, 7).unwrap())
+    /// );
+    /// assert!(
+    ///     !range.contains(LunisolarDate::from_ymd(2024, 1, false, 16).unwrap())
+    /// );
+    /// ```
+    #[inline]
+    pub fn contains(self, lunisolar_date: LunisolarDate) -> bool {
+        self.start <= lunisolar_date && lunisolar_date <= self.end
+    }
+
+    /// 計算與另一個農曆日期範圍的交集。如果沒有交集，就回傳 `None`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{LunisolarDate, LunisolarDateRange};
+    ///
+    /// let range_1 = LunisolarDateRange::new(
+    ///     LunisolarDate::from_ymd(2024, 1, false, 1).unwrap(),
+    ///     LunisolarDate::from_ymd(2024, 1, false, 15).unwrap(),
+    /// )
+    /// .unwrap();
+    /// let range_2 = LunisolarDateRange::new(
+    ///     LunisolarDate::from_ymd(2024, 1, false, 10).unwrap(),
+    ///     LunisolarDate::from_ymd(2024, 2, false, 1).unwrap(),
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(
+    ///     LunisolarDateRange::new(
+    ///         LunisolarDate::from_ymd(2024, 1, false, 10).unwrap(),
+    ///         LunisolarDate::from_ymd(2024, 1, false, 15).unwrap(),
+    ///     ),
+    ///     range_1.intersection(range_2)
+    /// );
+    /// ```
+    #[inline]
+    pub fn intersection(self, other: LunisolarDateRange) -> Option<LunisolarDateRange> {
+        Self::new(self.start.max(other.start), self.end.min(other.end))
+    }
+}
+
+impl IntoIterator for LunisolarDateRange {
+    type IntoIter = LunisolarDateRangeIter;
+    type Item = LunisolarDate;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl From<LunisolarDateRange> for SolarDateRange {
+    #[inline]
+    fn from(value: LunisolarDateRange) -> Self {
+        value.to_solar_date_range()
+    }
+}
+
+/// 依序走訪農曆日期範圍內每一天的迭代器。可以透過 `LunisolarDateRange::iter` 方法來取得。
+#[derive(Debug, Clone)]
+pub struct LunisolarDateRangeIter {
+    front:     LunisolarDate,
+    back:      LunisolarDate,
+    remaining: u32,
+}
+
+impl Iterator for LunisolarDateRangeIter {
+    type Item = LunisolarDate;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let lunisolar_date = self.front;
+
+        self.remaining -= 1;
+
+        if self.remaining > 0 {
+            self.front = match self.front.succ() {
+                Ok(lunisolar_date) => lunisolar_date,
+                Err(_) => unreachable!(),
+            };
+        }
+
+        Some(lunisolar_date)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.remaining as usize;
+
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for LunisolarDateRangeIter {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let lunisolar_date = self.back;
+
+        self.remaining -= 1;
+
+        if self.remaining > 0 {
+            self.back = match self.back.pred() {
+                Ok(lunisolar_date) => lunisolar_date,
+                Err(_) => unreachable!(),
+            };
+        }
+
+        Some(lunisolar_date)
+    }
+}
+
+impl ExactSizeIterator for LunisolarDateRangeIter {}
+
+impl FusedIterator for LunisolarDateRangeIter {}
+
+/// 額外的實作。
+impl LunisolarYear {
+    /// 取得此農曆年從正月初一到最後一天的農曆日期範圍。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{LunisolarDate, LunisolarYear, SolarYear};
+    ///
+    /// let lunisolar_year =
+    ///     LunisolarYear::from_solar_year(SolarYear::from_u16(2023)).unwrap();
+    ///
+    /// let range = lunisolar_year.range();
+    ///
+    /// assert_eq!(384, range.len_days());
+    /// assert_eq!(
+    ///     LunisolarDate::from_ymd(2023, 1, false, 1).unwrap(),
+    ///     range.start()
+    /// );
+    /// assert_eq!(
+    ///     LunisolarDate::from_ymd(2023, 12, false, 30).unwrap(),
+    ///     range.end()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn range(self) -> LunisolarDateRange {
+        unsafe {
+            LunisolarDateRange {
+                start: LunisolarDate::from_the_n_day_in_this_year_unsafe(self, 1),
+                end:   LunisolarDate::from_the_n_day_in_this_year_unsafe(
+                    self,
+                    self.get_total_days(),
+                ),
+            }
+        }
+    }
+}
+
+/// 額外的實作。
+impl LunisolarMonth {
+    /// 取得此農曆月從初一到最後一天的農曆日期範圍。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{LunisolarDate, LunisolarMonth};
+    ///
+    /// let range = LunisolarMonth::from_ym(2023, 2, true).unwrap().range();
+    ///
+    /// assert_eq!(29, range.len_days());
+    /// assert_eq!(
+    ///     LunisolarDate::from_ymd(2023, 2, true, 1).unwrap(),
+    ///     range.start()
+    /// );
+    /// assert_eq!(
+    ///     LunisolarDate::from_ymd(2023, 2, true, 29).unwrap(),
+    ///     range.end()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn range(self) -> LunisolarDateRange {
+        let start = self.first_day();
+        let end = self.last_day();
+
+        LunisolarDateRange {
+            start,
+            end,
+        }
+    }
+}
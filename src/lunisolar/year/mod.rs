@@ -1,8 +1,13 @@
 mod built_in_traits;
 use core::fmt::{self, Display, Formatter};
 
-use super::{BIG_MONTHS, LEAP_MONTHS, LunisolarOutOfRangeError};
-use crate::{EarthlyBranch, HeavenlyStems, LunarMonth, LunarYear, SolarYear, Zodiac};
+use super::{
+    BIG_MONTHS, LEAP_MONTHS, LunisolarDate, LunisolarOutOfRangeError, NEW_YEAR_DIFFERENCE,
+};
+use crate::{
+    EarthlyBranch, HeavenlyStems, LunarMonth, LunarYear, SolarDate, SolarDateRange, SolarDay,
+    SolarMonth, SolarYear, Zodiac,
+};
 
 /// 最小支援的農曆西曆年。
 pub const MIN_YEAR_IN_SOLAR_CALENDAR: u16 = 1901;
@@ -420,6 +425,131 @@ impl LunisolarYear {
             }
         }
     }
+
+    /// 取得此農曆年的農曆新年(正月初一)所對應的西曆日期。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{LunisolarYear, SolarDate, SolarYear};
+    ///
+    /// let lunisolar_year =
+    ///     LunisolarYear::from_solar_year(SolarYear::from_u16(2024)).unwrap();
+    ///
+    /// assert_eq!(
+    ///     SolarDate::from_ymd(2024, 2, 10).unwrap(),
+    ///     lunisolar_year.new_year_solar_date()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn new_year_solar_date(self) -> SolarDate {
+        let diff = NEW_YEAR_DIFFERENCE[(self.to_u16() - MIN_YEAR_IN_SOLAR_CALENDAR) as usize];
+
+        // 農曆新年一定落在西曆的一月或二月
+        let (month, day) = if diff < 31 { (1, diff + 1) } else { (2, diff - 31 + 1) };
+
+        unsafe {
+            SolarDate::from_solar_year_month_day_unsafe(
+                self.0,
+                SolarMonth::from_u8_unsafe(month),
+                SolarDay::from_u8_unsafe(day),
+            )
+        }
+    }
+
+    /// 取得此農曆年的除夕，也就是臘月的最後一天(廿九或三十)。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{LunisolarDate, LunisolarYear, SolarYear};
+    ///
+    /// let lunisolar_year_2023 =
+    ///     LunisolarYear::from_solar_year(SolarYear::from_u16(2023)).unwrap();
+    ///
+    /// let lunisolar_year_2024 =
+    ///     LunisolarYear::from_solar_year(SolarYear::from_u16(2024)).unwrap();
+    ///
+    /// assert_eq!(
+    ///     LunisolarDate::from_ymd(2023, 12, false, 30).unwrap(),
+    ///     lunisolar_year_2023.new_years_eve()
+    /// );
+    /// assert_eq!(
+    ///     LunisolarDate::from_ymd(2024, 12, false, 29).unwrap(),
+    ///     lunisolar_year_2024.new_years_eve()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn new_years_eve(self) -> LunisolarDate {
+        unsafe { LunisolarDate::from_the_n_day_in_this_year_unsafe(self, self.get_total_days()) }
+    }
+
+    /// 取得此農曆年從農曆新年(正月初一)到除夕所對應的西曆日期範圍。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{LunisolarYear, SolarDate, SolarYear};
+    ///
+    /// let lunisolar_year =
+    ///     LunisolarYear::from_solar_year(SolarYear::from_u16(2024)).unwrap();
+    ///
+    /// let solar_date_range = lunisolar_year.solar_date_range();
+    ///
+    /// assert_eq!(
+    ///     SolarDate::from_ymd(2024, 2, 10).unwrap(),
+    ///     solar_date_range.start()
+    /// );
+    /// assert_eq!(
+    ///     SolarDate::from_ymd(2025, 1, 28).unwrap(),
+    ///     solar_date_range.end()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn solar_date_range(self) -> SolarDateRange {
+        match SolarDateRange::new(self.new_year_solar_date(), self.new_years_eve().to_solar_date())
+        {
+            Some(solar_date_range) => solar_date_range,
+            None => unreachable!(),
+        }
+    }
+
+    /// 計算此西曆年下的農曆年共有幾個月(含閏月)。
+    #[inline]
+    pub(crate) const fn get_total_months(self) -> u8 {
+        if self.get_leap_lunar_month().is_some() { 13 } else { 12 }
+    }
+
+    /// 計算農曆月在此西曆年下的農曆年中是第幾個月(從 `0` 開始，含閏月)。必須先確認傳入的月份在該年是存在的。
+    #[inline]
+    pub(crate) const fn get_lunar_month_index(self, lunar_month: LunarMonth) -> u8 {
+        let month = lunar_month.to_u8();
+
+        match self.get_leap_lunar_month() {
+            Some(leap_lunar_month)
+                if month > leap_lunar_month.to_u8()
+                    || (month == leap_lunar_month.to_u8() && lunar_month.is_leap_month()) =>
+            {
+                month
+            },
+            _ => month - 1,
+        }
+    }
+
+    /// 取得此西曆年下的農曆年中的第 `index` 個月(從 `0` 開始，含閏月)。必須先確認傳入的索引值小於該年的月數。
+    #[inline]
+    pub(crate) const fn get_lunar_month_by_index(self, index: u8) -> LunarMonth {
+        let (month, leap) = match self.get_leap_lunar_month() {
+            Some(leap_lunar_month) if index >= leap_lunar_month.to_u8() => {
+                let leap_month = leap_lunar_month.to_u8();
+
+                if index == leap_month { (leap_month, true) } else { (index, false) }
+            },
+            _ => (index + 1, false),
+        };
+
+        unsafe { LunarMonth::from_u8_with_leap_unsafe(month, leap) }
+    }
 }
 
 /// 額外的實作。
@@ -0,0 +1,34 @@
+use core::str::FromStr;
+
+use super::NaYin;
+use crate::{FiveElements, LunarYear, Sexagenary};
+
+impl From<Sexagenary> for NaYin {
+    #[inline]
+    fn from(value: Sexagenary) -> Self {
+        NaYin::from_sexagenary(value)
+    }
+}
+
+impl From<LunarYear> for NaYin {
+    #[inline]
+    fn from(value: LunarYear) -> Self {
+        value.nayin()
+    }
+}
+
+impl From<NaYin> for FiveElements {
+    #[inline]
+    fn from(value: NaYin) -> Self {
+        value.to_five_elements()
+    }
+}
+
+impl FromStr for NaYin {
+    type Err = ();
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_str(s).ok_or(())
+    }
+}
@@ -0,0 +1,69 @@
+use crate::FiveElements;
+
+/// 列舉三十納音，從甲子、乙丑的海中金開始，到壬戌、癸亥的大海水結束。
+pub(super) const THE_NA_YIN: [(&str, &str); 30] = [
+    ("海中金", "海中金"),
+    ("爐中火", "炉中火"),
+    ("大林木", "大林木"),
+    ("路旁土", "路旁土"),
+    ("劍鋒金", "剑锋金"),
+    ("山頭火", "山头火"),
+    ("澗下水", "涧下水"),
+    ("城頭土", "城头土"),
+    ("白蠟金", "白蜡金"),
+    ("楊柳木", "杨柳木"),
+    ("泉中水", "泉中水"),
+    ("屋上土", "屋上土"),
+    ("霹靂火", "霹雳火"),
+    ("松柏木", "松柏木"),
+    ("長流水", "长流水"),
+    ("沙中金", "沙中金"),
+    ("山下火", "山下火"),
+    ("平地木", "平地木"),
+    ("壁上土", "壁上土"),
+    ("金箔金", "金箔金"),
+    ("覆燈火", "覆灯火"),
+    ("天河水", "天河水"),
+    ("大驛土", "大驿土"),
+    ("釵釧金", "钗钏金"),
+    ("桑柘木", "桑柘木"),
+    ("大溪水", "大溪水"),
+    ("沙中土", "沙中土"),
+    ("天上火", "天上火"),
+    ("石榴木", "石榴木"),
+    ("大海水", "大海水"),
+];
+
+/// 三十納音各自所屬的五行。
+pub(super) const THE_NA_YIN_FIVE_ELEMENTS: [FiveElements; 30] = [
+    FiveElements::Metal,
+    FiveElements::Fire,
+    FiveElements::Wood,
+    FiveElements::Earth,
+    FiveElements::Metal,
+    FiveElements::Fire,
+    FiveElements::Water,
+    FiveElements::Earth,
+    FiveElements::Metal,
+    FiveElements::Wood,
+    FiveElements::Water,
+    FiveElements::Earth,
+    FiveElements::Fire,
+    FiveElements::Wood,
+    FiveElements::Water,
+    FiveElements::Metal,
+    FiveElements::Fire,
+    FiveElements::Wood,
+    FiveElements::Earth,
+    FiveElements::Metal,
+    FiveElements::Fire,
+    FiveElements::Water,
+    FiveElements::Earth,
+    FiveElements::Metal,
+    FiveElements::Wood,
+    FiveElements::Water,
+    FiveElements::Earth,
+    FiveElements::Fire,
+    FiveElements::Wood,
+    FiveElements::Water,
+];
@@ -0,0 +1,128 @@
+mod built_in_traits;
+mod chinese;
+mod parse;
+
+use core::fmt::{self, Display, Formatter};
+
+use chinese::{THE_NA_YIN, THE_NA_YIN_FIVE_ELEMENTS};
+
+use crate::{ChineseVariant, FiveElements, Sexagenary};
+
+/// 納音，六十干支每兩個一組配一個納音，共三十個(海中金、爐中火、...、大海水)。
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub struct NaYin(u8);
+
+impl Display for NaYin {
+    /// Formats the value using the given formatter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::LunarYear;
+    ///
+    /// let na_yin = LunarYear::parse_str("甲辰").unwrap().nayin();
+    ///
+    /// assert_eq!("覆燈火", format!("{}", na_yin));
+    /// assert_eq!("覆灯火", format!("{:#}", na_yin));
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str(self.to_str(ChineseVariant::Simple))
+        } else {
+            f.write_str(self.to_str(ChineseVariant::Traditional))
+        }
+    }
+}
+
+/// 用以建立 `NaYin` 實體的關聯函數。
+impl NaYin {
+    /// 透過干支來取得 `NaYin` 實體。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{NaYin, Sexagenary};
+    ///
+    /// let na_yin = NaYin::from_sexagenary(Sexagenary::parse_str("乙丑").unwrap());
+    ///
+    /// assert_eq!("海中金", na_yin.to_string());
+    /// ```
+    #[inline]
+    pub const fn from_sexagenary(sexagenary: Sexagenary) -> Self {
+        NaYin(sexagenary.0 / 2)
+    }
+}
+
+/// 將 `NaYin` 實體轉成其它型別的方法。
+impl NaYin {
+    /// 取得納音的序數(海中金為 `1`，大海水為 `30`)。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::LunarYear;
+    ///
+    /// assert_eq!(21, LunarYear::parse_str("甲辰").unwrap().nayin().ordinal());
+    /// ```
+    #[inline]
+    pub const fn ordinal(self) -> u8 {
+        self.0 + 1
+    }
+
+    /// 取得 `NaYin` 實體所代表的納音字串。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{ChineseVariant, LunarYear};
+    ///
+    /// let na_yin = LunarYear::parse_str("丙寅").unwrap().nayin();
+    ///
+    /// assert_eq!("爐中火", na_yin.to_str(ChineseVariant::Traditional));
+    /// assert_eq!("炉中火", na_yin.to_str(ChineseVariant::Simple));
+    /// ```
+    #[inline]
+    pub const fn to_str(self, chinese_variant: ChineseVariant) -> &'static str {
+        let i = self.0 as usize;
+
+        match chinese_variant {
+            ChineseVariant::Simple => THE_NA_YIN[i].1,
+            ChineseVariant::Traditional => THE_NA_YIN[i].0,
+        }
+    }
+
+    /// 取得納音所屬的五行。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{FiveElements, LunarYear};
+    ///
+    /// let na_yin = LunarYear::parse_str("甲辰").unwrap().nayin();
+    ///
+    /// assert_eq!(FiveElements::Fire, na_yin.to_five_elements());
+    /// ```
+    #[inline]
+    pub const fn to_five_elements(self) -> FiveElements {
+        THE_NA_YIN_FIVE_ELEMENTS[self.0 as usize]
+    }
+
+    /// 取得此納音所對應的兩個干支(陽干支在前)。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{NaYin, Sexagenary};
+    ///
+    /// let na_yin = NaYin::parse_str("大海水").unwrap();
+    /// let [a, b] = na_yin.to_sexagenaries();
+    ///
+    /// assert_eq!("壬戌", a.to_str());
+    /// assert_eq!("癸亥", b.to_str());
+    /// ```
+    #[inline]
+    pub const fn to_sexagenaries(self) -> [Sexagenary; 2] {
+        [Sexagenary(self.0 * 2), Sexagenary(self.0 * 2 + 1)]
+    }
+}
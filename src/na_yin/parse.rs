@@ -0,0 +1,28 @@
+use super::{NaYin, THE_NA_YIN};
+
+/// 用以解析字串的關聯函數。
+impl NaYin {
+    /// 透過海中金、爐中火、...、大海水等字串來取得 `NaYin` 實體。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::NaYin;
+    ///
+    /// let na_yin = NaYin::parse_str("覆燈火").unwrap();
+    ///
+    /// assert_eq!(na_yin, NaYin::parse_str("覆灯火").unwrap());
+    /// ```
+    #[inline]
+    pub fn parse_str<S: AsRef<str>>(s: S) -> Option<Self> {
+        let s = s.as_ref();
+
+        for (i, t) in THE_NA_YIN.iter().enumerate() {
+            if s == t.0 || s == t.1 {
+                return Some(NaYin(i as u8));
+            }
+        }
+
+        None
+    }
+}
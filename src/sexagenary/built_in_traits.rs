@@ -0,0 +1,101 @@
+use core::{
+    ops::{Add, AddAssign, Sub, SubAssign},
+    str::FromStr,
+};
+
+use super::{Sexagenary, SexagenaryError};
+use crate::{EarthlyBranch, HeavenlyStems, LunarYear};
+
+impl FromStr for Sexagenary {
+    type Err = SexagenaryError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_str(s)
+    }
+}
+
+impl TryFrom<(HeavenlyStems, EarthlyBranch)> for Sexagenary {
+    type Error = SexagenaryError;
+
+    #[inline]
+    fn try_from(value: (HeavenlyStems, EarthlyBranch)) -> Result<Self, Self::Error> {
+        Self::from_stem_branch(value.0, value.1)
+    }
+}
+
+impl From<LunarYear> for Sexagenary {
+    #[inline]
+    fn from(value: LunarYear) -> Self {
+        value.to_sexagenary()
+    }
+}
+
+impl From<Sexagenary> for LunarYear {
+    #[inline]
+    fn from(value: Sexagenary) -> Self {
+        LunarYear::from_sexagenary(value)
+    }
+}
+
+impl From<Sexagenary> for HeavenlyStems {
+    #[inline]
+    fn from(value: Sexagenary) -> Self {
+        value.to_heavenly_stems()
+    }
+}
+
+impl From<Sexagenary> for EarthlyBranch {
+    #[inline]
+    fn from(value: Sexagenary) -> Self {
+        value.to_earthly_branch()
+    }
+}
+
+impl AsRef<str> for Sexagenary {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.to_str()
+    }
+}
+
+impl Add<i32> for Sexagenary {
+    type Output = Sexagenary;
+
+    #[inline]
+    fn add(self, rhs: i32) -> Self::Output {
+        self.wrapping_add(rhs)
+    }
+}
+
+impl AddAssign<i32> for Sexagenary {
+    #[inline]
+    fn add_assign(&mut self, rhs: i32) {
+        *self = self.wrapping_add(rhs);
+    }
+}
+
+impl Sub<i32> for Sexagenary {
+    type Output = Sexagenary;
+
+    #[inline]
+    fn sub(self, rhs: i32) -> Self::Output {
+        self.wrapping_sub(rhs)
+    }
+}
+
+impl SubAssign<i32> for Sexagenary {
+    #[inline]
+    fn sub_assign(&mut self, rhs: i32) {
+        *self = self.wrapping_sub(rhs);
+    }
+}
+
+impl Sub<Sexagenary> for Sexagenary {
+    type Output = u8;
+
+    #[inline]
+    fn sub(self, rhs: Sexagenary) -> Self::Output {
+        self.steps_since(rhs)
+    }
+}
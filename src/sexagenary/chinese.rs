@@ -1,6 +1,6 @@
-/// 列舉農曆六十個年份名稱：甲子、乙丑、丙寅、...、癸亥。
+/// 列舉六十干支的名稱：甲子、乙丑、丙寅、...、癸亥。
 #[rustfmt::skip]
-pub(super) const THE_LUNAR_YEARS: [&str; 60] = [
+pub(crate) const THE_SEXAGENARY_CYCLE: [&str; 60] = [
     "甲子", "乙丑", "丙寅", "丁卯", "戊辰", "己巳", "庚午", "辛未", "壬申", "癸酉", // 0 / 12
     "甲戌", "乙亥", "丙子", "丁丑", "戊寅", "己卯", "庚辰", "辛巳", "壬午", "癸未", // 10
     "甲申", "乙酉", "丙戌", "丁亥", "戊子", "己丑", "庚寅", "辛卯", "壬辰", "癸巳", // 8
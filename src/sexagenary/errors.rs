@@ -0,0 +1,17 @@
+use core::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+/// 錯誤的干支。
+#[derive(Debug, Eq, PartialEq)]
+pub struct SexagenaryError;
+
+impl Display for SexagenaryError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("the sexagenary is incorrect")
+    }
+}
+
+impl Error for SexagenaryError {}
@@ -0,0 +1,51 @@
+use core::iter::FusedIterator;
+
+use super::Sexagenary;
+
+/// 六十干支的迭代器，從甲子開始到癸亥。
+#[derive(Debug, Clone)]
+pub struct SexagenaryIter {
+    pub(super) front: u8,
+    pub(super) back:  u8,
+}
+
+impl Iterator for SexagenaryIter {
+    type Item = Sexagenary;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            let sexagenary = Sexagenary(self.front);
+
+            self.front += 1;
+
+            Some(sexagenary)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.back - self.front) as usize;
+
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for SexagenaryIter {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+
+            Some(Sexagenary(self.back))
+        } else {
+            None
+        }
+    }
+}
+
+impl ExactSizeIterator for SexagenaryIter {}
+
+impl FusedIterator for SexagenaryIter {}
@@ -0,0 +1,319 @@
+mod built_in_traits;
+mod chinese;
+mod errors;
+mod iter;
+mod parse;
+mod pillar;
+mod xun;
+
+use core::fmt::{self, Display, Formatter};
+
+pub(crate) use chinese::THE_SEXAGENARY_CYCLE;
+pub use errors::*;
+pub use iter::*;
+pub use pillar::*;
+
+use crate::{EarthlyBranch, FiveElements, HeavenlyStems, NaYin, YinYang};
+
+/// 干支，由天干加地支組成，六十個一輪(甲子、乙丑、...、癸亥)。可用來表示年、月、日、時。
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub struct Sexagenary(pub(crate) u8);
+
+impl Display for Sexagenary {
+    /// Formats the value using the given formatter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{
+    ///     EarthlyBranch, HeavenlyStems, Sexagenary,
+    /// };
+    ///
+    /// let sexagenary = Sexagenary::from_stem_branch(
+    ///     HeavenlyStems::Fifth,
+    ///     EarthlyBranch::Eleventh,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!("戊戌", format!("{}", sexagenary));
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.to_str())
+    }
+}
+
+/// 用以建立 `Sexagenary` 實體的關聯函數。
+impl Sexagenary {
+    /// 透過干支的序數(甲子為 `1`，癸亥為 `60`)來取得 `Sexagenary` 實體。
+    ///
+    /// # Safety
+    /// 必須先確認傳入的整數是合法的。
+    #[inline]
+    pub const unsafe fn from_ordinal_unsafe(ordinal: u8) -> Self {
+        Self(ordinal - 1)
+    }
+
+    /// 透過干支的序數(甲子為 `1`，癸亥為 `60`)來取得 `Sexagenary` 實體。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::Sexagenary;
+    ///
+    /// assert_eq!("甲子", Sexagenary::from_ordinal(1).unwrap().to_str());
+    /// assert_eq!("癸亥", Sexagenary::from_ordinal(60).unwrap().to_str());
+    /// assert!(Sexagenary::from_ordinal(61).is_err());
+    /// ```
+    #[inline]
+    pub const fn from_ordinal(ordinal: u8) -> Result<Self, SexagenaryError> {
+        if ordinal >= 1 && ordinal <= 60 {
+            Ok(unsafe { Self::from_ordinal_unsafe(ordinal) })
+        } else {
+            Err(SexagenaryError)
+        }
+    }
+
+    /// 透過中國天干地支來取得 `Sexagenary` 實體。天干和地支的陰陽必須相同。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{
+    ///     EarthlyBranch, HeavenlyStems, Sexagenary,
+    /// };
+    ///
+    /// let sexagenary = Sexagenary::from_stem_branch(
+    ///     HeavenlyStems::Fifth,
+    ///     EarthlyBranch::Eleventh,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(35, sexagenary.ordinal());
+    ///
+    /// assert!(
+    ///     Sexagenary::from_stem_branch(
+    ///         HeavenlyStems::First,
+    ///         EarthlyBranch::Second
+    ///     )
+    ///     .is_err()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn from_stem_branch(
+        heavenly_stems: HeavenlyStems,
+        earthly_branch: EarthlyBranch,
+    ) -> Result<Self, SexagenaryError> {
+        let h = heavenly_stems.ordinal();
+        let e = earthly_branch.ordinal();
+
+        let index = if h == e {
+            h
+        } else if h < e {
+            let diff = e - h;
+
+            if diff & 1 == 1 {
+                return Err(SexagenaryError);
+            }
+
+            h + (12 - diff) * 5
+        } else {
+            let diff = h - e;
+
+            if diff & 1 == 1 {
+                return Err(SexagenaryError);
+            }
+
+            e + diff * 6
+        } - 1;
+
+        Ok(Sexagenary(index))
+    }
+}
+
+/// 將 `Sexagenary` 實體轉成其它型別的方法。
+impl Sexagenary {
+    /// 取得干支的序數(甲子為 `1`，癸亥為 `60`)。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::Sexagenary;
+    ///
+    /// assert_eq!(41, Sexagenary::parse_str("甲辰").unwrap().ordinal());
+    /// ```
+    #[inline]
+    pub const fn ordinal(self) -> u8 {
+        self.0 + 1
+    }
+
+    /// 取得 `Sexagenary` 實體所代表的干支字串。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::Sexagenary;
+    ///
+    /// assert_eq!("甲辰", Sexagenary::from_ordinal(41).unwrap().to_str());
+    /// ```
+    #[inline]
+    pub const fn to_str(self) -> &'static str {
+        THE_SEXAGENARY_CYCLE[self.0 as usize]
+    }
+
+    /// 取得天干。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{HeavenlyStems, Sexagenary};
+    ///
+    /// let sexagenary = Sexagenary::parse_str("戊戌").unwrap();
+    ///
+    /// assert_eq!(HeavenlyStems::Fifth, sexagenary.to_heavenly_stems());
+    /// ```
+    #[inline]
+    pub const fn to_heavenly_stems(self) -> HeavenlyStems {
+        unsafe { HeavenlyStems::from_ordinal_unsafe(self.0 % 10 + 1) }
+    }
+
+    /// 取得地支。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{EarthlyBranch, Sexagenary};
+    ///
+    /// let sexagenary = Sexagenary::parse_str("戊戌").unwrap();
+    ///
+    /// assert_eq!(EarthlyBranch::Eleventh, sexagenary.to_earthly_branch());
+    /// ```
+    #[inline]
+    pub const fn to_earthly_branch(self) -> EarthlyBranch {
+        unsafe { EarthlyBranch::from_ordinal_unsafe(self.0 % 12 + 1) }
+    }
+
+    /// 取得干支的五行，以天干的五行為準。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{FiveElements, Sexagenary};
+    ///
+    /// let sexagenary = Sexagenary::parse_str("戊戌").unwrap();
+    ///
+    /// assert_eq!(FiveElements::Earth, sexagenary.to_five_elements());
+    /// ```
+    #[inline]
+    pub const fn to_five_elements(self) -> FiveElements {
+        self.to_heavenly_stems().to_five_elements()
+    }
+
+    /// 取得干支的陰陽。天干和地支的陰陽必定相同。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{Sexagenary, YinYang};
+    ///
+    /// let sexagenary = Sexagenary::parse_str("癸亥").unwrap();
+    ///
+    /// assert_eq!(YinYang::Yin, sexagenary.to_yin_yang());
+    /// ```
+    #[inline]
+    pub const fn to_yin_yang(self) -> YinYang {
+        self.to_heavenly_stems().to_yin_yang()
+    }
+
+    /// 取得干支的納音。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::Sexagenary;
+    ///
+    /// let sexagenary = Sexagenary::parse_str("甲子").unwrap();
+    ///
+    /// assert_eq!("海中金", sexagenary.nayin().to_string());
+    /// ```
+    #[inline]
+    pub const fn nayin(self) -> NaYin {
+        NaYin::from_sexagenary(self)
+    }
+}
+
+/// 干支的循環計算方法。
+impl Sexagenary {
+    /// 往後推算 `n` 個干支，超過癸亥後會再從甲子開始。`n` 可以是負數。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::Sexagenary;
+    ///
+    /// let sexagenary = Sexagenary::parse_str("癸亥").unwrap();
+    ///
+    /// assert_eq!("甲子", sexagenary.wrapping_add(1).to_str());
+    /// assert_eq!("壬戌", sexagenary.wrapping_add(-1).to_str());
+    /// assert_eq!("癸亥", sexagenary.wrapping_add(600).to_str());
+    /// ```
+    #[inline]
+    pub const fn wrapping_add(self, n: i32) -> Self {
+        Sexagenary((self.0 as i32 + n.rem_euclid(60)).rem_euclid(60) as u8)
+    }
+
+    /// 往前推算 `n` 個干支，超過甲子後會再從癸亥開始。`n` 可以是負數。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::Sexagenary;
+    ///
+    /// let sexagenary = Sexagenary::parse_str("甲子").unwrap();
+    ///
+    /// assert_eq!("癸亥", sexagenary.wrapping_sub(1).to_str());
+    /// assert_eq!("乙丑", sexagenary.wrapping_sub(-1).to_str());
+    /// ```
+    #[inline]
+    pub const fn wrapping_sub(self, n: i32) -> Self {
+        Sexagenary((self.0 as i32 - n.rem_euclid(60)).rem_euclid(60) as u8)
+    }
+
+    /// 計算從 `other` 往後推算幾個干支(`0` 到 `59`)會到達此干支。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::Sexagenary;
+    ///
+    /// let a = Sexagenary::parse_str("甲子").unwrap();
+    /// let b = Sexagenary::parse_str("癸亥").unwrap();
+    ///
+    /// assert_eq!(59, b.steps_since(a));
+    /// assert_eq!(1, a.steps_since(b));
+    /// ```
+    #[inline]
+    pub const fn steps_since(self, other: Sexagenary) -> u8 {
+        (self.0 + 60 - other.0) % 60
+    }
+
+    /// 取得六十干支的迭代器，從甲子開始到癸亥。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::Sexagenary;
+    ///
+    /// let mut iter = Sexagenary::iter();
+    ///
+    /// assert_eq!(60, iter.len());
+    /// assert_eq!("甲子", iter.next().unwrap().to_str());
+    /// assert_eq!("癸亥", iter.next_back().unwrap().to_str());
+    /// ```
+    #[inline]
+    pub const fn iter() -> SexagenaryIter {
+        SexagenaryIter {
+            front: 0, back: 60
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use super::{Sexagenary, SexagenaryError, THE_SEXAGENARY_CYCLE};
+
+/// 用以解析字串的關聯函數。
+impl Sexagenary {
+    /// 透過甲子、乙丑、...、癸亥等字串來取得 `Sexagenary` 實體。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{
+    ///     EarthlyBranch, HeavenlyStems, Sexagenary,
+    /// };
+    ///
+    /// let sexagenary = Sexagenary::parse_str("戊戌").unwrap();
+    ///
+    /// assert_eq!(HeavenlyStems::Fifth, sexagenary.to_heavenly_stems());
+    /// assert_eq!(EarthlyBranch::Eleventh, sexagenary.to_earthly_branch());
+    /// ```
+    #[inline]
+    pub fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, SexagenaryError> {
+        let s = s.as_ref();
+
+        for (i, &t) in THE_SEXAGENARY_CYCLE.iter().enumerate() {
+            if s == t {
+                return Ok(Sexagenary(i as u8));
+            }
+        }
+
+        Err(SexagenaryError)
+    }
+}
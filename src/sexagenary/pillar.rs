@@ -0,0 +1,106 @@
+use chrono::Timelike;
+
+use super::Sexagenary;
+use crate::{EarthlyBranch, HeavenlyStems};
+
+/// 晚子時(23:00 到 24:00)的歸屬方式。不論使用哪一種方式，晚子時的時柱都是以隔天的日干，利用五鼠遁來推算。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LateZiHourRule {
+    /// 晚子時屬於隔天，日柱使用隔天的。年柱和月柱不受影響，仍以實際的日期和時間來推算。
+    #[default]
+    NextDay,
+    /// 晚子時屬於當天，日柱使用當天的，但時柱的時干仍以隔天的日干來推算。例如丁酉日的晚子時，日柱為丁酉，時柱則以隔天的戊日推算為壬子，而不是以丁日推算的庚子。
+    CurrentDay,
+}
+
+/// 干支的推算方法。
+impl Sexagenary {
+    /// 利用五虎遁，透過年干和月份(寅月為 `1`，丑月為 `12`)來取得月柱。
+    ///
+    /// # Safety
+    /// 必須先確認傳入的月份介於 `1` 到 `12` 之間。
+    #[inline]
+    pub(crate) const unsafe fn from_year_stem_and_month_unsafe(
+        year_stem: HeavenlyStems,
+        month: u8,
+    ) -> Self {
+        // 甲己之年丙作首，乙庚之歲戊為頭，丙辛之歲尋庚上，丁壬壬寅順水流，若問戊癸何處起，甲寅之上好追求
+        let first_month = (year_stem.ordinal() - 1) % 5 * 12 + 2;
+
+        Sexagenary((first_month + month - 1) % 60)
+    }
+
+    /// 利用五鼠遁，透過日干和時辰的地支來取得時柱。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{
+    ///     EarthlyBranch, HeavenlyStems, Sexagenary,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     "甲子",
+    ///     Sexagenary::from_day_stem_and_hour_branch(
+    ///         HeavenlyStems::First,
+    ///         EarthlyBranch::First
+    ///     )
+    ///     .to_str()
+    /// );
+    /// assert_eq!(
+    ///     "丙戌",
+    ///     Sexagenary::from_day_stem_and_hour_branch(
+    ///         HeavenlyStems::Seventh,
+    ///         EarthlyBranch::Eleventh
+    ///     )
+    ///     .to_str()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn from_day_stem_and_hour_branch(
+        day_stem: HeavenlyStems,
+        hour_branch: EarthlyBranch,
+    ) -> Self {
+        // 甲己還加甲，乙庚丙作初，丙辛從戊起，丁壬庚子居，戊癸何方發，壬子是真途
+        let first_hour = (day_stem.ordinal() - 1) % 5 * 12;
+
+        Sexagenary(first_hour + hour_branch.ordinal() - 1)
+    }
+
+    /// 利用五鼠遁，透過日干和時間來取得時柱。23:00 到 24:00 的晚子時不論歸屬於哪一天(見 `LateZiHourRule`)，都使用隔天的日干。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{
+    ///     HeavenlyStems, Sexagenary, chrono::NaiveTime,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     "甲子",
+    ///     Sexagenary::from_day_stem_and_time(
+    ///         HeavenlyStems::First,
+    ///         NaiveTime::from_hms_opt(0, 30, 0).unwrap()
+    ///     )
+    ///     .to_str()
+    /// );
+    /// assert_eq!(
+    ///     "丙子",
+    ///     Sexagenary::from_day_stem_and_time(
+    ///         HeavenlyStems::First,
+    ///         NaiveTime::from_hms_opt(23, 30, 0).unwrap()
+    ///     )
+    ///     .to_str()
+    /// );
+    /// ```
+    #[inline]
+    pub fn from_day_stem_and_time<T: Timelike>(day_stem: HeavenlyStems, time: T) -> Self {
+        let day_stem = if time.hour() == 23 {
+            unsafe { HeavenlyStems::from_ordinal_unsafe(day_stem.ordinal() % 10 + 1) }
+        } else {
+            day_stem
+        };
+
+        Self::from_day_stem_and_hour_branch(day_stem, EarthlyBranch::from_time(time))
+    }
+}
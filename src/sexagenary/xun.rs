@@ -0,0 +1,160 @@
+use super::{Sexagenary, SexagenaryError};
+use crate::{EarthlyBranch, FourPillars, HeavenlyStems, LunarYear};
+
+/// 旬與旬空(空亡)。六十干支每十個為一旬，以甲開頭，共六旬(甲子旬、甲戌旬、甲申旬、甲午旬、甲辰旬、甲寅旬)。每旬十干配十支，剩下的兩個地支即為此旬的空亡。
+impl Sexagenary {
+    /// 取得此干支所在旬的旬首，例如甲子旬的旬首為甲子。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::Sexagenary;
+    ///
+    /// assert_eq!("甲子", Sexagenary::parse_str("癸酉").unwrap().xun().to_str());
+    /// assert_eq!("甲戌", Sexagenary::parse_str("甲戌").unwrap().xun().to_str());
+    /// ```
+    #[inline]
+    pub const fn xun(self) -> Sexagenary {
+        Sexagenary(self.0 / 10 * 10)
+    }
+
+    /// 取得此干支所在旬的兩個空亡地支。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{EarthlyBranch, Sexagenary};
+    ///
+    /// assert_eq!(
+    ///     [EarthlyBranch::Eleventh, EarthlyBranch::Twelfth],
+    ///     Sexagenary::parse_str("甲子").unwrap().void_branches()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn void_branches(self) -> [EarthlyBranch; 2] {
+        let head = self.0 / 10 * 10;
+
+        unsafe {
+            [
+                EarthlyBranch::from_ordinal_unsafe((head + 10) % 12 + 1),
+                EarthlyBranch::from_ordinal_unsafe((head + 11) % 12 + 1),
+            ]
+        }
+    }
+
+    /// 透過天干和地支(例如日干和日支)取得所在旬的旬首。天干和地支的陰陽必須相同。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{
+    ///     EarthlyBranch, HeavenlyStems, Sexagenary,
+    /// };
+    ///
+    /// let xun = Sexagenary::xun_of(HeavenlyStems::Fifth, EarthlyBranch::Eleventh)
+    ///     .unwrap();
+    ///
+    /// assert_eq!("甲午", xun.to_str());
+    /// ```
+    #[inline]
+    pub const fn xun_of(
+        heavenly_stems: HeavenlyStems,
+        earthly_branch: EarthlyBranch,
+    ) -> Result<Sexagenary, SexagenaryError> {
+        match Sexagenary::from_stem_branch(heavenly_stems, earthly_branch) {
+            Ok(sexagenary) => Ok(sexagenary.xun()),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// 透過天干和地支(例如日干和日支)取得所在旬的兩個空亡地支。天干和地支的陰陽必須相同。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{
+    ///     EarthlyBranch, HeavenlyStems, Sexagenary,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     [EarthlyBranch::Fifth, EarthlyBranch::Sixth],
+    ///     Sexagenary::void_branches_of(
+    ///         HeavenlyStems::Fifth,
+    ///         EarthlyBranch::Eleventh
+    ///     )
+    ///     .unwrap()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn void_branches_of(
+        heavenly_stems: HeavenlyStems,
+        earthly_branch: EarthlyBranch,
+    ) -> Result<[EarthlyBranch; 2], SexagenaryError> {
+        match Sexagenary::from_stem_branch(heavenly_stems, earthly_branch) {
+            Ok(sexagenary) => Ok(sexagenary.void_branches()),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+impl LunarYear {
+    /// 取得此年所在旬的旬首。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::LunarYear;
+    ///
+    /// let lunar_year = LunarYear::parse_str("甲辰").unwrap();
+    ///
+    /// assert_eq!("甲辰", lunar_year.xun().to_str());
+    /// assert_eq!(
+    ///     "甲辰旬",
+    ///     format!("{}旬", LunarYear::parse_str("癸丑").unwrap().xun())
+    /// );
+    /// ```
+    #[inline]
+    pub const fn xun(self) -> Sexagenary {
+        self.to_sexagenary().xun()
+    }
+
+    /// 取得此年所在旬的兩個空亡地支。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{EarthlyBranch, LunarYear};
+    ///
+    /// let lunar_year = LunarYear::parse_str("甲辰").unwrap();
+    ///
+    /// assert_eq!(
+    ///     [EarthlyBranch::Third, EarthlyBranch::Fourth],
+    ///     lunar_year.void_branches()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn void_branches(self) -> [EarthlyBranch; 2] {
+        self.to_sexagenary().void_branches()
+    }
+}
+
+impl FourPillars {
+    /// 以日柱取得四柱的空亡地支。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{EarthlyBranch, FourPillars};
+    ///
+    /// let four_pillars = FourPillars::parse_str("戊子 庚申 庚辰 丙戌").unwrap();
+    ///
+    /// assert_eq!(
+    ///     [EarthlyBranch::Ninth, EarthlyBranch::Tenth],
+    ///     four_pillars.void_branches()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn void_branches(self) -> [EarthlyBranch; 2] {
+        self.to_day_pillar().void_branches()
+    }
+}
@@ -0,0 +1,207 @@
+/// 列舉每個西曆月份的「節」(小寒、立春、驚蟄、清明、立夏、芒種、小暑、立秋、白露、寒露、立冬、大雪)在該月份中最早可能落在第幾天。
+pub(super) const SOLAR_TERM_MIN_DAYS: [u8; 12] = [4, 3, 4, 4, 4, 4, 6, 6, 6, 7, 6, 6];
+
+/// 列舉西元1901~2101年每個西曆月份的「節」的交節時刻(以 VSOP87 理論推算太陽視黃經)，以距離該月份第 `SOLAR_TERM_MIN_DAYS` 天 00:00 的分鐘數表示，四捨五入到分鐘。時間以東八區(UTC+8)為準。
+pub(super) const SOLAR_TERM_MINUTES: [[u16; 12]; 201] = [
+    [3353, 2620, 3731, 2625, 3711, 3997, 3188, 3766, 3910, 3367, 3515, 3053], // 1901
+    [3712, 2978, 4088, 2978, 4059, 4340, 3526, 4102, 4247, 3705, 3858, 3401], // 1902
+    [4064, 3331, 4439, 3326, 4406, 4687, 3877, 4456, 4602, 4062, 4213, 3755], // 1903
+    [4417, 3684, 3352, 2239, 3319, 3601, 2792, 3372, 3518, 2976, 3125, 2665], // 1904
+    [3327, 2596, 3706, 2595, 3674, 3954, 3140, 3717, 3862, 3320, 3470, 3011], // 1905
+    [3674, 2944, 4056, 2947, 4029, 4309, 3495, 4072, 4216, 3675, 3827, 3369], // 1906
+    [4032, 3299, 4407, 3295, 4374, 4653, 3839, 4416, 4562, 4023, 4176, 3719], // 1907
+    [4381, 3647, 3314, 2200, 3279, 3559, 2748, 3327, 3472, 2931, 3082, 2624], // 1908
+    [3285, 2553, 3661, 2550, 3631, 3914, 3104, 3683, 3827, 3283, 3433, 2975], // 1909
+    [3638, 2908, 4017, 2903, 3980, 4257, 3441, 4017, 4162, 3621, 3774, 3317], // 1910
+    [3981, 3250, 4359, 3245, 4321, 4598, 3785, 4364, 4513, 3975, 4127, 3668], // 1911
+    [4328, 3594, 3261, 2148, 3227, 3508, 2697, 3277, 3426, 2887, 3039, 2579], // 1912
+    [3238, 2503, 3609, 2496, 3575, 3854, 3039, 3616, 3762, 3224, 3378, 2921], // 1913
+    [3583, 2849, 3956, 2842, 3920, 4200, 3387, 3965, 4113, 3575, 3731, 3277], // 1914
+    [3940, 3206, 4308, 3189, 4263, 4540, 3728, 4308, 4457, 3921, 4078, 3624], // 1915
+    [4288, 3554, 3218, 2098, 3170, 3446, 2634, 3215, 3365, 2828, 2982, 2526], // 1916
+    [3190, 2458, 3565, 2450, 3526, 3803, 2990, 3570, 3720, 3182, 3337, 2881], // 1917
+    [3544, 2813, 3921, 2805, 3878, 4151, 3332, 3908, 4056, 3520, 3679, 3226], // 1918
+    [3892, 3159, 4266, 3149, 4222, 4497, 3681, 4258, 4408, 3873, 4032, 3578], // 1919
+    [4241, 3507, 3171, 2055, 3132, 3411, 2599, 3178, 3327, 2789, 2945, 2490], // 1920
+    [3154, 2420, 3525, 2409, 3484, 3762, 2947, 3524, 3670, 3131, 3286, 2831], // 1921
+    [3497, 2767, 3874, 2758, 3833, 4110, 3298, 3877, 4026, 3489, 3645, 3191], // 1922
+    [3854, 3121, 4225, 3106, 4178, 4454, 3642, 4225, 4377, 3843, 4000, 3545], // 1923
+    [4206, 3470, 3133, 2013, 3086, 3362, 2549, 3132, 3286, 2752, 2909, 2453], // 1924
+    [3113, 2377, 3480, 2363, 3438, 3717, 2905, 3487, 3640, 3108, 3266, 2812], // 1925
+    [3474, 2738, 3840, 2718, 3789, 4062, 3246, 3824, 3976, 3445, 3608, 3159], // 1926
+    [3825, 3090, 4190, 3066, 4133, 4405, 3590, 4171, 4325, 3795, 3957, 3506], // 1927
+    [4171, 3437, 3097, 1975, 3044, 3317, 2504, 3088, 3242, 2710, 2870, 2417], // 1928
+    [3082, 2349, 3452, 2331, 3401, 3671, 2852, 3429, 3580, 3047, 3208, 2756], // 1929
+    [3423, 2691, 3797, 2678, 3747, 4018, 3200, 3777, 3928, 3398, 3560, 3111], // 1930
+    [3776, 3041, 4142, 3021, 4090, 4362, 3546, 4125, 4277, 3747, 3910, 3460], // 1931
+    [4125, 3390, 3050, 1926, 2995, 3268, 2452, 3032, 3183, 2650, 2810, 2358], // 1932
+    [3023, 2289, 3392, 2271, 3342, 3618, 2805, 3386, 3538, 3004, 3163, 2711], // 1933
+    [3377, 2644, 3746, 2624, 3691, 3962, 3145, 3724, 3876, 3345, 3507, 3057], // 1934
+    [3722, 2989, 4090, 2966, 4032, 4302, 3486, 4068, 4224, 3696, 3858, 3405], // 1935
+    [4067, 3329, 2989, 1867, 2937, 3211, 2398, 2983, 3141, 2613, 2775, 2322], // 1936
+    [2984, 2246, 3345, 2222, 3291, 3563, 2746, 3325, 3479, 2951, 3115, 2666], // 1937
+    [3331, 2595, 3694, 2569, 3635, 3907, 3091, 3673, 3828, 3301, 3468, 3022], // 1938
+    [3688, 2951, 4046, 2918, 3981, 4252, 3438, 4023, 4182, 3657, 3824, 3377], // 1939
+    [4044, 3308, 2964, 1835, 2896, 3164, 2348, 2932, 3089, 2562, 2727, 2278], // 1940
+    [2944, 2210, 3310, 2185, 3250, 3519, 2703, 3286, 3444, 2918, 3084, 2636], // 1941
+    [3302, 2569, 3669, 2544, 3607, 3873, 3052, 3630, 3786, 3262, 3431, 2987], // 1942
+    [3655, 2920, 4019, 2891, 3954, 4219, 3399, 3978, 4135, 3610, 3779, 3333], // 1943
+    [3999, 3263, 2921, 1794, 2860, 3131, 2316, 2899, 3056, 2529, 2695, 2248], // 1944
+    [2915, 2180, 3278, 2152, 3217, 3486, 2667, 3245, 3398, 2869, 3034, 2588], // 1945
+    [3256, 2524, 3625, 2499, 3562, 3829, 3011, 3592, 3747, 3221, 3387, 2940], // 1946
+    [3606, 2870, 3968, 2840, 3903, 4171, 3356, 3941, 4101, 3577, 3744, 3296], // 1947
+    [3960, 3222, 2878, 1750, 2812, 3081, 2264, 2846, 3005, 2480, 2647, 2198], // 1948
+    [2861, 2123, 3219, 2092, 3157, 3427, 2612, 3195, 3354, 2831, 3000, 2553], // 1949
+    [3219, 2481, 3575, 2445, 3505, 3771, 2953, 3535, 3694, 3172, 3344, 2902], // 1950
+    [3570, 2834, 3927, 2793, 3849, 4113, 3294, 3877, 4038, 3516, 3687, 3242], // 1951
+    [3910, 3173, 2827, 1695, 2754, 3020, 2205, 2791, 2954, 2432, 2602, 2156], // 1952
+    [2822, 2086, 3183, 2053, 3112, 3376, 2555, 3135, 3293, 2770, 2941, 2497], // 1953
+    [3165, 2431, 3529, 2399, 3458, 3721, 2899, 3479, 3638, 3117, 3291, 2848], // 1954
+    [3516, 2778, 3871, 2739, 3798, 4063, 3246, 3830, 3992, 3472, 3645, 3203], // 1955
+    [3870, 3132, 2785, 1651, 2710, 2976, 2158, 2740, 2899, 2376, 2546, 2102], // 1956
+    [2771, 2035, 3130, 1999, 3058, 3325, 2508, 3092, 3252, 2730, 2900, 2456], // 1957
+    [3124, 2389, 3485, 2352, 3409, 3672, 2853, 3437, 3599, 3079, 3252, 2810], // 1958
+    [3478, 2742, 3837, 2703, 3759, 4020, 3200, 3784, 3948, 3430, 3602, 3157], // 1959
+    [3823, 3083, 2736, 1604, 2663, 2929, 2113, 2700, 2865, 2349, 2522, 2078], // 1960
+    [2743, 2002, 3095, 1962, 3021, 3286, 2467, 3048, 3209, 2691, 2866, 2426], // 1961
+    [3095, 2357, 3450, 2314, 3370, 3631, 2811, 3394, 3555, 3038, 3215, 2777], // 1962
+    [3447, 2708, 3797, 2659, 3712, 3974, 3158, 3745, 3912, 3396, 3572, 3133], // 1963
+    [3802, 3065, 2716, 1578, 2631, 2892, 2072, 2656, 2819, 2301, 2475, 2033], // 1964
+    [2702, 1966, 3061, 1927, 2982, 3242, 2421, 3005, 3168, 2651, 2826, 2385], // 1965
+    [3054, 2318, 3411, 2277, 3331, 3590, 2767, 3349, 3512, 2997, 3175, 2738], // 1966
+    [3408, 2671, 3762, 2625, 3678, 3936, 3113, 3695, 3858, 3341, 3517, 3078], // 1967
+    [3746, 3008, 2658, 1521, 2576, 2839, 2022, 2607, 2771, 2254, 2429, 1988], // 1968
+    [2657, 1919, 3011, 1875, 2930, 3192, 2372, 2954, 3115, 2597, 2771, 2331], // 1969
+    [3002, 2266, 3359, 2222, 3274, 3532, 2710, 3294, 3458, 2942, 3118, 2677], // 1970
+    [3345, 2606, 3695, 2556, 3608, 3869, 3051, 3640, 3810, 3299, 3477, 3036], // 1971
+    [3702, 2960, 2608, 1469, 2521, 2782, 1963, 2549, 2715, 2202, 2379, 1939], // 1972
+    [2605, 1864, 2953, 1814, 2867, 3127, 2307, 2893, 3059, 2547, 2728, 2290], // 1973
+    [2960, 2220, 3307, 2165, 3214, 3472, 2651, 3237, 3405, 2895, 3078, 2645], // 1974
+    [3318, 2579, 3666, 2522, 3567, 3822, 2999, 3585, 3753, 3242, 3423, 2986], // 1975
+    [3657, 2920, 2568, 1427, 2475, 2731, 1911, 2498, 2668, 2158, 2339, 1901], // 1976
+    [2571, 1833, 2924, 1786, 2836, 3092, 2268, 2850, 3016, 2504, 2686, 2251], // 1977
+    [2923, 2187, 3278, 2139, 3189, 3443, 2617, 3198, 3362, 2851, 3034, 2600], // 1978
+    [3272, 2532, 3620, 2478, 3527, 3785, 2965, 3551, 3720, 3210, 3393, 2958], // 1979
+    [3629, 2890, 2537, 1395, 2445, 2704, 1884, 2469, 2633, 2119, 2298, 1861], // 1980
+    [2533, 1795, 2885, 1745, 2795, 3053, 2232, 2817, 2983, 2469, 2648, 2211], // 1981
+    [2883, 2145, 3235, 2093, 3140, 3396, 2575, 3162, 3332, 2822, 3004, 2568], // 1982
+    [3239, 2500, 3587, 2444, 3491, 3746, 2923, 3510, 3680, 3171, 3352, 2914], // 1983
+    [3581, 2839, 2485, 1342, 2391, 2649, 1829, 2418, 2590, 2082, 2265, 1828], // 1984
+    [2495, 1752, 2836, 1694, 2743, 3000, 2179, 2764, 2933, 2424, 2609, 2176], // 1985
+    [2848, 2108, 3192, 2046, 3091, 3344, 2521, 3106, 3275, 2767, 2953, 2521], // 1986
+    [3193, 2452, 3534, 2384, 3426, 3679, 2859, 3449, 3624, 3120, 3306, 2872], // 1987
+    [3544, 2803, 2447, 1299, 2342, 2595, 1773, 2360, 2532, 2024, 2209, 1774], // 1988
+    [2446, 1707, 2794, 1650, 2694, 2945, 2119, 2704, 2874, 2367, 2554, 2121], // 1989
+    [2793, 2054, 3139, 1993, 3036, 3286, 2460, 3045, 3217, 2714, 2904, 2474], // 1990
+    [3148, 2408, 3492, 2345, 3387, 3638, 2813, 3397, 3567, 3061, 3248, 2816], // 1991
+    [3489, 2748, 2392, 1245, 2289, 2542, 1720, 2307, 2478, 1971, 2157, 1724], // 1992
+    [2396, 1657, 2743, 1597, 2642, 2895, 2072, 2658, 2828, 2320, 2505, 2074], // 1993
+    [2748, 2011, 3098, 1952, 2994, 3245, 2419, 3004, 3175, 2669, 2856, 2423], // 1994
+    [3094, 2353, 3436, 2288, 3330, 3582, 2761, 3352, 3529, 3027, 3216, 2782], // 1995
+    [3451, 2708, 2350, 1202, 2246, 2501, 1680, 2269, 2442, 1939, 2127, 1694], // 1996
+    [2364, 1622, 2704, 1556, 2600, 2853, 2029, 2616, 2789, 2285, 2474, 2045], // 1997
+    [2718, 1977, 3057, 1905, 2943, 3194, 2370, 2960, 3136, 2636, 2828, 2402], // 1998
+    [3077, 2337, 3418, 2265, 3301, 3549, 2725, 3314, 3490, 2988, 3178, 2748], // 1999
+    [3421, 2680, 2323, 1172, 2210, 2459, 1634, 2223, 2399, 1898, 2088, 1657], // 2000
+    [2329, 1589, 2673, 1525, 2565, 2814, 1987, 2572, 2746, 2245, 2437, 2009], // 2001
+    [2684, 1944, 3028, 1878, 2917, 3165, 2336, 2919, 3091, 2589, 2782, 2354], // 2002
+    [3028, 2286, 3365, 2213, 3251, 3500, 2676, 3264, 3440, 2941, 3133, 2705], // 2003
+    [3379, 2636, 2276, 1123, 2163, 2414, 1591, 2180, 2353, 1849, 2039, 1609], // 2004
+    [2283, 1543, 2625, 1474, 2513, 2762, 1937, 2523, 2697, 2193, 2382, 1953], // 2005
+    [2627, 1887, 2969, 1816, 2851, 3097, 2271, 2861, 3039, 2541, 2735, 2307], // 2006
+    [2980, 2238, 3318, 2165, 3200, 3447, 2622, 3211, 3389, 2892, 3084, 2654], // 2007
+    [3325, 2580, 2219, 1066, 2104, 2352, 1527, 2116, 2294, 1797, 1991, 1562], // 2008
+    [2234, 1490, 2568, 1414, 2451, 2699, 1874, 2461, 2638, 2140, 2336, 1912], // 2009
+    [2589, 1848, 2926, 1771, 2804, 3049, 2222, 2809, 2985, 2487, 2683, 2258], // 2010
+    [2935, 2193, 3270, 2112, 3143, 3387, 2562, 3153, 3334, 2839, 3035, 2609], // 2011
+    [3284, 2542, 2181, 1026, 2060, 2306, 1481, 2071, 2249, 1752, 1946, 1519], // 2012
+    [2194, 1453, 2535, 1383, 2418, 2663, 1835, 2420, 2596, 2098, 2294, 1869], // 2013
+    [2544, 1803, 2882, 1727, 2759, 3003, 2175, 2763, 2941, 2448, 2647, 2224], // 2014
+    [2901, 2159, 3236, 2079, 3113, 3358, 2532, 3121, 3300, 2803, 2999, 2573], // 2015
+    [3248, 2506, 2144, 988, 2022, 2269, 1443, 2033, 2211, 1713, 1908, 1481],  // 2016
+    [2156, 1414, 2493, 1337, 2371, 2617, 1791, 2380, 2559, 2062, 2258, 1833], // 2017
+    [2509, 1769, 2848, 1693, 2725, 2969, 2142, 2731, 2910, 2415, 2612, 2186], // 2018
+    [2859, 2114, 3190, 2031, 3063, 3306, 2481, 3073, 3257, 2766, 2964, 2538], // 2019
+    [3210, 2463, 2097, 938, 1972, 2218, 1394, 1986, 2168, 1675, 1874, 1450],  // 2020
+    [2123, 1379, 2454, 1295, 2327, 2572, 1745, 2334, 2513, 2019, 2219, 1797], // 2021
+    [2474, 1731, 2804, 1640, 2666, 2906, 2078, 2669, 2852, 2362, 2565, 2146], // 2022
+    [2825, 2083, 3156, 1993, 3019, 3258, 2431, 3023, 3207, 2716, 2916, 2493], // 2023
+    [3169, 2427, 2063, 902, 1930, 2170, 1340, 1929, 2111, 1620, 1820, 1397],  // 2024
+    [2073, 1330, 2407, 1249, 2277, 2517, 1685, 2271, 2452, 1961, 2164, 1744], // 2025
+    [2423, 1682, 2759, 1600, 2629, 2868, 2037, 2623, 2801, 2309, 2512, 2092], // 2026
+    [2770, 2026, 3099, 1937, 2965, 3206, 2377, 2967, 3148, 2657, 2858, 2438], // 2027
+    [3115, 2371, 2005, 843, 1872, 2116, 1290, 1881, 2062, 1568, 1767, 1345],  // 2028
+    [2022, 1281, 2357, 1198, 2228, 2470, 1642, 2232, 2412, 1918, 2117, 1694], // 2029
+    [2370, 1628, 2703, 1541, 2566, 2804, 1975, 2567, 2753, 2265, 2468, 2047], // 2030
+    [2723, 1978, 3051, 1888, 2915, 3156, 2329, 2923, 3110, 2623, 2825, 2403], // 2031
+    [3076, 2329, 1960, 797, 1826, 2068, 1241, 1833, 2018, 1530, 1734, 1313],  // 2032
+    [1988, 1241, 2312, 1148, 2174, 2413, 1585, 2176, 2360, 1874, 2081, 1665], // 2033
+    [2344, 1601, 2672, 1506, 2529, 2766, 1937, 2529, 2714, 2227, 2433, 2017], // 2034
+    [2696, 1952, 3022, 1854, 2875, 3111, 2281, 2874, 3062, 2578, 2784, 2365], // 2035
+    [3043, 2300, 1932, 766, 1789, 2027, 1197, 1789, 1975, 1489, 1694, 1276],  // 2036
+    [1954, 1211, 2286, 1124, 2149, 2387, 1555, 2143, 2325, 1838, 2044, 1627], // 2037
+    [2307, 1563, 2635, 1469, 2491, 2725, 1892, 2481, 2666, 2181, 2391, 1976], // 2038
+    [2656, 1913, 2983, 1816, 2838, 3075, 2246, 2838, 3024, 2537, 2743, 2325], // 2039
+    [3003, 2260, 1891, 725, 1749, 1988, 1159, 1750, 1934, 1445, 1649, 1230],  // 2040
+    [1908, 1165, 2238, 1072, 2094, 2330, 1498, 2088, 2273, 1787, 1993, 1576], // 2041
+    [2255, 1513, 2586, 1420, 2443, 2678, 1847, 2439, 2625, 2140, 2347, 1929], // 2042
+    [2605, 1859, 2928, 1760, 2782, 3018, 2188, 2781, 2970, 2487, 2696, 2277], // 2043
+    [2952, 2204, 1831, 663, 1685, 1924, 1096, 1688, 1876, 1393, 1602, 1185],  // 2044
+    [1862, 1116, 2185, 1017, 2039, 2277, 1448, 2039, 2225, 1740, 1950, 1535], // 2045
+    [2216, 1471, 2538, 1365, 2380, 2612, 1780, 2373, 2563, 2082, 2294, 1881], // 2046
+    [2562, 1818, 2885, 1713, 2728, 2961, 2130, 2726, 2918, 2437, 2647, 2231], // 2047
+    [2909, 2164, 1794, 625, 1644, 1878, 1047, 1639, 1828, 1346, 1557, 1140],  // 2048
+    [1818, 1073, 2143, 974, 1992, 2223, 1389, 1978, 2165, 1685, 1898, 1486],  // 2049
+    [2168, 1424, 2493, 1323, 2342, 2574, 1742, 2332, 2521, 2040, 2253, 1841], // 2050
+    [2522, 1776, 2842, 1669, 2687, 2920, 2089, 2682, 2871, 2390, 2602, 2188], // 2051
+    [2868, 2123, 1749, 577, 1595, 1829, 1000, 1593, 1782, 1299, 1510, 1095],  // 2052
+    [1776, 1033, 2103, 934, 1953, 2187, 1357, 1950, 2138, 1656, 1866, 1452],  // 2053
+    [2132, 1388, 2455, 1283, 2298, 2527, 1693, 2287, 2479, 2002, 2216, 1803], // 2054
+    [2482, 1735, 2801, 1628, 2644, 2876, 2045, 2641, 2835, 2359, 2572, 2158], // 2055
+    [2835, 2087, 1712, 540, 1558, 1792, 962, 1556, 1747, 1269, 1483, 1071],   // 2056
+    [1750, 1002, 2067, 892, 1906, 2136, 1302, 1894, 2084, 1606, 1823, 1414],  // 2057
+    [2098, 1354, 2420, 1244, 2256, 2484, 1651, 2245, 2438, 1961, 2177, 1767], // 2058
+    [2449, 1704, 2768, 1592, 2604, 2832, 1999, 2592, 2786, 2310, 2525, 2113], // 2059
+    [2794, 2048, 1674, 499, 1513, 1741, 907, 1499, 1690, 1213, 1429, 1017],   // 2060
+    [1698, 954, 2021, 850, 1866, 2096, 1262, 1853, 2042, 1564, 1780, 1370],   // 2061
+    [2052, 1307, 2371, 1195, 2207, 2434, 1598, 2189, 2380, 1904, 2122, 1714], // 2062
+    [2397, 1651, 2714, 1537, 2548, 2777, 1945, 2540, 2733, 2257, 2472, 2060], // 2063
+    [2741, 1995, 1619, 444, 1458, 1690, 859, 1454, 1646, 1168, 1381, 969],    // 2064
+    [1649, 903, 1969, 794, 1805, 2032, 1196, 1789, 1982, 1506, 1722, 1312],   // 2065
+    [1994, 1249, 2314, 1137, 2148, 2376, 1542, 2137, 2333, 1861, 2079, 1668], // 2066
+    [2347, 1597, 2658, 1480, 2492, 2721, 1889, 2485, 2682, 2211, 2430, 2020], // 2067
+    [2699, 1949, 1569, 389, 1400, 1629, 797, 1391, 1585, 1113, 1333, 926],    // 2068
+    [1608, 861, 1922, 744, 1754, 1983, 1151, 1746, 1940, 1467, 1687, 1282],   // 2069
+    [1967, 1221, 2282, 1099, 2104, 2328, 1492, 2086, 2283, 1813, 2035, 1630], // 2070
+    [2316, 1570, 2632, 1450, 2455, 2678, 1842, 2439, 2638, 2168, 2388, 1980], // 2071
+    [2663, 1917, 1541, 363, 1373, 1600, 765, 1359, 1555, 1083, 1303, 896],    // 2072
+    [1578, 832, 1896, 719, 1727, 1950, 1110, 1700, 1893, 1421, 1644, 1240],   // 2073
+    [1926, 1181, 2244, 1065, 2073, 2297, 1461, 2053, 2248, 1777, 1999, 1594], // 2074
+    [2277, 1530, 2591, 1411, 2419, 2646, 1813, 2408, 2603, 2131, 2351, 1944], // 2075
+    [2627, 1879, 1500, 320, 1328, 1554, 720, 1314, 1509, 1034, 1253, 845],    // 2076
+    [1528, 783, 1846, 668, 1678, 1904, 1070, 1666, 1863, 1390, 1610, 1202],   // 2077
+    [1884, 1137, 2197, 1016, 2021, 2244, 1408, 2004, 2203, 1736, 1959, 1552], // 2078
+    [2233, 1483, 2541, 1357, 2362, 2586, 1751, 2349, 2550, 2083, 2307, 1900], // 2079
+    [2579, 1828, 1445, 262, 1270, 1497, 665, 1263, 1462, 994, 1218, 813],     // 2080
+    [1496, 746, 1802, 617, 1620, 1841, 1003, 1597, 1794, 1326, 1552, 1151],   // 2081
+    [1838, 1092, 2150, 963, 1963, 2182, 1345, 1941, 2142, 1677, 1904, 1501],  // 2082
+    [2186, 1438, 2496, 1310, 2311, 2532, 1695, 2292, 2494, 2029, 2255, 1851], // 2083
+    [2535, 1786, 1405, 220, 1223, 1442, 603, 1196, 1394, 927, 1153, 751],     // 2084
+    [1436, 689, 1750, 568, 1573, 1794, 956, 1549, 1747, 1280, 1507, 1107],    // 2085
+    [1793, 1046, 2104, 917, 1919, 2138, 1300, 1893, 2092, 1627, 1855, 1456],  // 2086
+    [2142, 1395, 2452, 1264, 2264, 2484, 1648, 2244, 2444, 1977, 2203, 1800], // 2087
+    [2485, 1738, 1357, 172, 1176, 1400, 566, 1163, 1364, 896, 1120, 716],     // 2088
+    [1401, 654, 1714, 530, 1532, 1750, 911, 1504, 1704, 1238, 1464, 1063],    // 2089
+    [1748, 1002, 2061, 876, 1876, 2095, 1256, 1852, 2055, 1593, 1822, 1419],  // 2090
+    [2102, 1350, 2406, 1220, 2223, 2445, 1611, 2209, 2413, 1951, 2180, 1778], // 2091
+    [2460, 1708, 1322, 134, 1136, 1357, 521, 1116, 1316, 851, 1080, 681],     // 2092
+    [1367, 618, 1674, 486, 1486, 1706, 870, 1467, 1669, 1206, 1435, 1037],    // 2093
+    [1724, 977, 2031, 840, 1835, 2052, 1214, 1811, 2016, 1555, 1786, 1388],   // 2094
+    [2075, 1327, 2382, 1191, 2186, 2400, 1561, 2158, 2363, 1902, 2132, 1731], // 2095
+    [2416, 1666, 1283, 95, 1095, 1314, 476, 1073, 1277, 815, 1046, 645],      // 2096
+    [1330, 582, 1638, 450, 1448, 1663, 821, 1413, 1613, 1151, 1383, 987],     // 2097
+    [1676, 929, 1984, 793, 1788, 2003, 1162, 1756, 1958, 1498, 1730, 1332],   // 2098
+    [2019, 1269, 2322, 1131, 2129, 2348, 1511, 2110, 2314, 1852, 2082, 1683], // 2099
+    [2369, 1620, 2674, 1483, 2481, 2698, 1859, 2454, 2655, 2191, 2420, 2020], // 2100
+    [2707, 1960, 3016, 1828, 2825, 3041, 2202, 2799, 3004, 2543, 2774, 2374], // 2101
+];
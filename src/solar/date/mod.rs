@@ -8,7 +8,11 @@ use core::{
 
 use chrono::prelude::*;
 
-use super::{SolarDateError, SolarDay, SolarDayError, SolarMonth, SolarOutOfRangeError, SolarYear};
+use super::{
+    SOLAR_TERM_MIN_DAYS, SOLAR_TERM_MINUTES, SolarDateError, SolarDay, SolarDayError, SolarMonth,
+    SolarOutOfRangeError, SolarYear,
+};
+use crate::{ChineseWeekday, MIN_YEAR_IN_SOLAR_CALENDAR, Sexagenary};
 
 /// 西曆年月日。
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -266,6 +270,25 @@ impl SolarDate {
     pub const fn to_solar_day(self) -> SolarDay {
         self.solar_day
     }
+
+    /// 取得星期幾。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{SolarDate, chrono::Weekday};
+    ///
+    /// let solar_date = SolarDate::from_ymd(2024, 2, 10).unwrap();
+    ///
+    /// assert_eq!(Weekday::Sat, solar_date.weekday());
+    /// ```
+    #[inline]
+    pub const fn weekday(self) -> Weekday {
+        // Rata Die 第 1 日(西元 1 年 1 月 1 日)是星期一
+        let i = (self.to_rata_die() - 1).rem_euclid(7) as u8;
+
+        unsafe { ChineseWeekday::from_ordinal_unsafe(i + 1) }.to_weekday()
+    }
 }
 
 /// 西曆日期相關計算方法。
@@ -354,3 +377,244 @@ impl SolarDate {
         }
     }
 }
+
+/// Rata Die 第 1 日(西元 1 年 1 月 1 日)與儒略日數的差距。
+const RATA_DIE_TO_JULIAN_DAY_NUMBER: i32 = 1721425;
+
+/// 西曆日期與連續日數(儒略日數、Rata Die)的轉換方法。
+impl SolarDate {
+    /// 透過 Rata Die(以西元 1 年 1 月 1 日為第 1 日的連續日數)來產生 `SolarDate` 實體。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::SolarDate;
+    ///
+    /// assert_eq!(
+    ///     SolarDate::from_ymd(1, 1, 1).unwrap(),
+    ///     SolarDate::from_rata_die(1).unwrap()
+    /// );
+    /// assert_eq!(
+    ///     SolarDate::from_ymd(2024, 2, 10).unwrap(),
+    ///     SolarDate::from_rata_die(738926).unwrap()
+    /// );
+    /// ```
+    pub const fn from_rata_die(rata_die: i32) -> Result<Self, SolarOutOfRangeError> {
+        let d0 = rata_die as i64 - 1;
+
+        let n400 = d0.div_euclid(146097);
+        let d1 = d0.rem_euclid(146097);
+        let n100 = d1 / 36524;
+        let d2 = d1 % 36524;
+        let n4 = d2 / 1461;
+        let d3 = d2 % 1461;
+        let n1 = d3 / 365;
+
+        let year = 400 * n400 + 100 * n100 + 4 * n4 + n1;
+
+        // 閏年的最後一天(第 366 天)仍屬於當年
+        let year = if n100 == 4 || n1 == 4 { year } else { year + 1 };
+
+        if year < 0 || year > u16::MAX as i64 {
+            return Err(SolarOutOfRangeError);
+        }
+
+        let solar_year = SolarYear::from_u16(year as u16);
+
+        let mut n = (rata_die as i64 - rata_die_of_new_year(year) + 1) as u16;
+
+        let leap_year = solar_year.is_leap();
+
+        let mut month = 1;
+
+        loop {
+            let days = match year_helper::get_days_in_month_with_leap_year(leap_year, month) {
+                Some(days) => days as u16,
+                None => unreachable!(),
+            };
+
+            if n <= days {
+                break;
+            }
+
+            n -= days;
+            month += 1;
+        }
+
+        Ok(unsafe {
+            SolarDate::from_solar_year_month_day_unsafe(
+                solar_year,
+                SolarMonth::from_u8_unsafe(month),
+                SolarDay::from_u8_unsafe(n as u8),
+            )
+        })
+    }
+
+    /// 透過儒略日數(Julian Day Number)來產生 `SolarDate` 實體。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::SolarDate;
+    ///
+    /// assert_eq!(
+    ///     SolarDate::from_ymd(2000, 1, 1).unwrap(),
+    ///     SolarDate::from_julian_day_number(2451545).unwrap()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn from_julian_day_number(
+        julian_day_number: i32,
+    ) -> Result<Self, SolarOutOfRangeError> {
+        match julian_day_number.checked_sub(RATA_DIE_TO_JULIAN_DAY_NUMBER) {
+            Some(rata_die) => Self::from_rata_die(rata_die),
+            None => Err(SolarOutOfRangeError),
+        }
+    }
+
+    /// 轉成 Rata Die(以西元 1 年 1 月 1 日為第 1 日的連續日數)。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::SolarDate;
+    ///
+    /// let solar_date = SolarDate::from_ymd(2024, 2, 10).unwrap();
+    ///
+    /// assert_eq!(738926, solar_date.to_rata_die());
+    /// ```
+    #[inline]
+    pub const fn to_rata_die(self) -> i32 {
+        (rata_die_of_new_year(self.solar_year.to_u16() as i64)
+            + self.the_n_day_in_this_year() as i64
+            - 1) as i32
+    }
+
+    /// 轉成儒略日數(Julian Day Number)。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::SolarDate;
+    ///
+    /// let solar_date = SolarDate::from_ymd(2000, 1, 1).unwrap();
+    ///
+    /// assert_eq!(2451545, solar_date.to_julian_day_number());
+    /// ```
+    #[inline]
+    pub const fn to_julian_day_number(self) -> i32 {
+        self.to_rata_die() + RATA_DIE_TO_JULIAN_DAY_NUMBER
+    }
+}
+
+/// 干支相關的計算方法。
+impl SolarDate {
+    /// 取得日柱，也就是此日的干支。以 1949-10-01 為甲子日來推算。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::SolarDate;
+    ///
+    /// let solar_date = SolarDate::from_ymd(2024, 2, 10).unwrap();
+    ///
+    /// assert_eq!("甲辰", solar_date.day_stem_branch().to_str());
+    /// ```
+    #[inline]
+    pub const fn day_stem_branch(self) -> Sexagenary {
+        // 1949-10-01 的儒略日數為 2433191，(2433191 + 49) 可以被 60 整除
+        let i = (self.to_julian_day_number() + 49).rem_euclid(60) as u8;
+
+        unsafe { Sexagenary::from_ordinal_unsafe(i + 1) }
+    }
+
+    /// 取得此日某個時間的時柱。23:00 到 24:00 的晚子時使用隔天的日干。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{SolarDate, chrono::NaiveTime};
+    ///
+    /// let solar_date = SolarDate::from_ymd(2008, 8, 8).unwrap();
+    ///
+    /// assert_eq!(
+    ///     "丙戌",
+    ///     solar_date
+    ///         .hour_stem_branch(NaiveTime::from_hms_opt(20, 0, 0).unwrap())
+    ///         .to_str()
+    /// );
+    /// assert_eq!(
+    ///     "戊子",
+    ///     solar_date
+    ///         .hour_stem_branch(NaiveTime::from_hms_opt(23, 0, 0).unwrap())
+    ///         .to_str()
+    /// );
+    /// ```
+    #[inline]
+    pub fn hour_stem_branch<T: Timelike>(self, time: T) -> Sexagenary {
+        Sexagenary::from_day_stem_and_time(self.day_stem_branch().to_heavenly_stems(), time)
+    }
+
+    /// 以「節」(小寒、立春、...、大雪)的交節時刻為月份的分界，取得此西曆日期的 `minutes` 分(從 00:00 起算，以東八區為準)所屬的節氣月，回傳以立春為一年之始的西曆年份，以及月份(寅月為 `1`，丑月為 `12`)。交節時刻精確到分鐘，交節的那一分鐘即屬於新的月份。
+    ///
+    /// # Safety
+    /// 必須先確認此西曆日期介於 1901-02-04 和 2101-12-31 之間。
+    pub(crate) const unsafe fn to_solar_term_month_unsafe(self, minutes: u16) -> (u16, u8) {
+        let year = self.solar_year.to_u16();
+        let month = self.solar_month.to_u8();
+
+        let i = (month - 1) as usize;
+
+        let offset =
+            (self.solar_day.to_u8() as i32 - SOLAR_TERM_MIN_DAYS[i] as i32) * 1440 + minutes as i32;
+
+        let solar_term_offset =
+            SOLAR_TERM_MINUTES[(year - MIN_YEAR_IN_SOLAR_CALENDAR) as usize][i] as i32;
+
+        // 二月的「節」(立春)開始寅月，一月的「節」(小寒)開始丑月
+        let solar_term_month =
+            if offset >= solar_term_offset { (month + 10) % 12 + 1 } else { (month + 9) % 12 + 1 };
+
+        // 立春之前的子月和丑月屬於前一年
+        if month <= 2 && solar_term_month >= 11 {
+            (year - 1, solar_term_month)
+        } else {
+            (year, solar_term_month)
+        }
+    }
+
+    /// 取得此西曆日期所在月份的「節」的交節時刻，以東八區(UTC+8)為準，精確到分鐘。
+    ///
+    /// # Safety
+    /// 必須先確認此西曆日期介於 1901-01-01 和 2101-12-31 之間。
+    pub(crate) unsafe fn solar_term_date_time_unsafe(self) -> NaiveDateTime {
+        let year = self.solar_year.to_u16();
+        let month = self.solar_month.to_u8();
+
+        let i = (month - 1) as usize;
+
+        let minutes = SOLAR_TERM_MINUTES[(year - MIN_YEAR_IN_SOLAR_CALENDAR) as usize][i] as u32;
+
+        let date = match NaiveDate::from_ymd_opt(
+            year as i32,
+            month as u32,
+            SOLAR_TERM_MIN_DAYS[i] as u32 + minutes / 1440,
+        ) {
+            Some(date) => date,
+            None => unreachable!(),
+        };
+
+        match date.and_hms_opt(minutes % 1440 / 60, minutes % 60, 0) {
+            Some(date_time) => date_time,
+            None => unreachable!(),
+        }
+    }
+}
+
+/// 計算西曆某年一月一日的 Rata Die。
+#[inline]
+const fn rata_die_of_new_year(year: i64) -> i64 {
+    let y = year - 1;
+
+    365 * y + y.div_euclid(4) - y.div_euclid(100) + y.div_euclid(400) + 1
+}
@@ -1,11 +1,15 @@
+mod constants;
 mod date;
 mod day;
 mod errors;
 mod month;
+mod range;
 mod year;
 
+use constants::*;
 pub use date::*;
 pub use day::*;
 pub use errors::*;
 pub use month::*;
+pub use range::*;
 pub use year::*;
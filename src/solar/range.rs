@@ -0,0 +1,235 @@
+use core::iter::FusedIterator;
+
+use chrono::prelude::*;
+
+use super::SolarDate;
+
+/// 西曆日期範圍，包含起始日與結束日。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SolarDateRange {
+    start: SolarDate,
+    end:   SolarDate,
+}
+
+/// 用以建立 `SolarDateRange` 結構實體的關聯函數。
+impl SolarDateRange {
+    /// 利用起始日和結束日(包含)來產生 `SolarDateRange` 實體。如果起始日比結束日晚，就回傳 `None`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{SolarDate, SolarDateRange};
+    ///
+    /// let start = SolarDate::from_ymd(2024, 2, 10).unwrap();
+    /// let end = SolarDate::from_ymd(2024, 2, 24).unwrap();
+    ///
+    /// assert!(SolarDateRange::new(start, end).is_some());
+    /// assert!(SolarDateRange::new(end, start).is_none());
+    /// ```
+    #[inline]
+    pub const fn new(start: SolarDate, end: SolarDate) -> Option<Self> {
+        if start.cmp(&end).is_le() {
+            Some(Self {
+                start,
+                end,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+/// 將 `SolarDateRange` 結構實體轉成其它型別的方法。
+impl SolarDateRange {
+    /// 取得起始日。
+    #[inline]
+    pub const fn start(self) -> SolarDate {
+        self.start
+    }
+
+    /// 取得結束日(包含)。
+    #[inline]
+    pub const fn end(self) -> SolarDate {
+        self.end
+    }
+
+    /// 走訪範圍內的每一天。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{SolarDate, SolarDateRange};
+    ///
+    /// let start = SolarDate::from_ymd(2024, 2, 28).unwrap();
+    /// let end = SolarDate::from_ymd(2024, 3, 1).unwrap();
+    ///
+    /// let range = SolarDateRange::new(start, end).unwrap();
+    ///
+    /// let mut iter = range.iter();
+    ///
+    /// assert_eq!(Some(start), iter.next());
+    /// assert_eq!(Some(end), iter.next_back());
+    /// assert_eq!(Some(SolarDate::from_ymd(2024, 2, 29).unwrap()), iter.next());
+    /// assert_eq!(None, iter.next());
+    /// ```
+    #[inline]
+    pub fn iter(self) -> SolarDateRangeIter {
+        SolarDateRangeIter {
+            front:     self.start.to_naive_date(),
+            back:      self.end.to_naive_date(),
+            remaining: self.len_days(),
+        }
+    }
+}
+
+/// 西曆日期範圍相關計算方法。
+impl SolarDateRange {
+    /// 計算範圍內共有幾天。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{SolarDate, SolarDateRange};
+    ///
+    /// let start = SolarDate::from_ymd(2024, 2, 10).unwrap();
+    /// let end = SolarDate::from_ymd(2024, 3, 9).unwrap();
+    ///
+    /// assert_eq!(29, SolarDateRange::new(start, end).unwrap().len_days());
+    /// ```
+    #[inline]
+    pub fn len_days(self) -> u32 {
+        (self.end.to_naive_date() - self.start.to_naive_date()).num_days() as u32 + 1
+    }
+
+    /// 判斷某個西曆日期是否在範圍內。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{SolarDate, SolarDateRange};
+    ///
+    /// let start = SolarDate::from_ymd(2024, 2, 10).unwrap();
+    /// let end = SolarDate::from_ymd(2024, 2, 24).unwrap();
+    ///
+    /// let range = SolarDateRange::new(start, end).unwrap();
+    ///
+    /// assert!(range.contains(SolarDate::from_ymd(2024, 2, 14).unwrap()));
+    /// assert!(!range.contains(SolarDate::from_ymd(2024, 2, 25).unwrap()));
+    /// ```
+    #[inline]
+    pub const fn contains(self, solar_date: SolarDate) -> bool {
+        self.start.cmp(&solar_date).is_le() && solar_date.cmp(&self.end).is_le()
+    }
+
+    /// 計算與另一個西曆日期範圍的交集。如果沒有交集，就回傳 `None`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{SolarDate, SolarDateRange};
+    ///
+    /// let range_1 = SolarDateRange::new(
+    ///     SolarDate::from_ymd(2024, 2, 10).unwrap(),
+    ///     SolarDate::from_ymd(2024, 2, 24).unwrap(),
+    /// )
+    /// .unwrap();
+    /// let range_2 = SolarDateRange::new(
+    ///     SolarDate::from_ymd(2024, 2, 20).unwrap(),
+    ///     SolarDate::from_ymd(2024, 3, 1).unwrap(),
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(
+    ///     SolarDateRange::new(
+    ///         SolarDate::from_ymd(2024, 2, 20).unwrap(),
+    ///         SolarDate::from_ymd(2024, 2, 24).unwrap(),
+    ///     ),
+    ///     range_1.intersection(range_2)
+    /// );
+    /// ```
+    #[inline]
+    pub fn intersection(self, other: SolarDateRange) -> Option<SolarDateRange> {
+        Self::new(self.start.max(other.start), self.end.min(other.end))
+    }
+}
+
+impl IntoIterator for SolarDateRange {
+    type IntoIter = SolarDateRangeIter;
+    type Item = SolarDate;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// 依序走訪西曆日期範圍內每一天的迭代器。可以透過 `SolarDateRange::iter` 方法來取得。
+#[derive(Debug, Clone)]
+pub struct SolarDateRangeIter {
+    front:     NaiveDate,
+    back:      NaiveDate,
+    remaining: u32,
+}
+
+impl Iterator for SolarDateRangeIter {
+    type Item = SolarDate;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let date = self.front;
+
+        self.remaining -= 1;
+
+        if self.remaining > 0 {
+            self.front = match date.succ_opt() {
+                Some(date) => date,
+                None => unreachable!(),
+            };
+        }
+
+        match SolarDate::from_date(date) {
+            Ok(solar_date) => Some(solar_date),
+            Err(_) => unreachable!(),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.remaining as usize;
+
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for SolarDateRangeIter {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let date = self.back;
+
+        self.remaining -= 1;
+
+        if self.remaining > 0 {
+            self.back = match date.pred_opt() {
+                Some(date) => date,
+                None => unreachable!(),
+            };
+        }
+
+        match SolarDate::from_date(date) {
+            Ok(solar_date) => Some(solar_date),
+            Err(_) => unreachable!(),
+        }
+    }
+}
+
+impl ExactSizeIterator for SolarDateRangeIter {}
+
+impl FusedIterator for SolarDateRangeIter {}
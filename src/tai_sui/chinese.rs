@@ -0,0 +1,72 @@
+/// 列舉犯太歲的類別：本命年、沖太歲、刑太歲、害太歲、破太歲。
+pub(super) const THE_TAI_SUI_CONFLICTS: [(&str, &str); 5] = [
+    ("本命年", "本命年"),
+    ("沖太歲", "冲太岁"),
+    ("刑太歲", "刑太岁"),
+    ("害太歲", "害太岁"),
+    ("破太歲", "破太岁"),
+];
+
+/// 六十太歲星君，從甲子年的金辨大將軍開始，到癸亥年的虞程大將軍結束。名號採用民間廟宇常見的六十太歲名錄，部分名號在其它典籍中有不同的寫法，例如甲子年也作金赤、丙午年也作文哲。
+pub(super) const THE_TAI_SUI_NAMES: [(&str, &str); 60] = [
+    ("金辨大將軍", "金辨大将军"),
+    ("陳材大將軍", "陈材大将军"),
+    ("耿章大將軍", "耿章大将军"),
+    ("沈興大將軍", "沈兴大将军"),
+    ("趙達大將軍", "赵达大将军"),
+    ("郭燦大將軍", "郭灿大将军"),
+    ("王清大將軍", "王清大将军"),
+    ("李素大將軍", "李素大将军"),
+    ("劉旺大將軍", "刘旺大将军"),
+    ("康志大將軍", "康志大将军"),
+    ("施廣大將軍", "施广大将军"),
+    ("任保大將軍", "任保大将军"),
+    ("郭嘉大將軍", "郭嘉大将军"),
+    ("汪文大將軍", "汪文大将军"),
+    ("曾光大將軍", "曾光大将军"),
+    ("伍仲大將軍", "伍仲大将军"),
+    ("董德大將軍", "董德大将军"),
+    ("鄭但大將軍", "郑但大将军"),
+    ("陸明大將軍", "陆明大将军"),
+    ("魏仁大將軍", "魏仁大将军"),
+    ("方傑大將軍", "方杰大将军"),
+    ("蔣崇大將軍", "蒋崇大将军"),
+    ("白敏大將軍", "白敏大将军"),
+    ("封齊大將軍", "封齐大将军"),
+    ("鄒鏜大將軍", "邹镗大将军"),
+    ("潘佑大將軍", "潘佑大将军"),
+    ("鄔桓大將軍", "邬桓大将军"),
+    ("范寧大將軍", "范宁大将军"),
+    ("彭泰大將軍", "彭泰大将军"),
+    ("徐單大將軍", "徐单大将军"),
+    ("章詞大將軍", "章词大将军"),
+    ("楊仙大將軍", "杨仙大将军"),
+    ("管仲大將軍", "管仲大将军"),
+    ("唐傑大將軍", "唐杰大将军"),
+    ("姜武大將軍", "姜武大将军"),
+    ("謝燾大將軍", "谢焘大将军"),
+    ("虞起大將軍", "虞起大将军"),
+    ("湯信大將軍", "汤信大将军"),
+    ("賀諤大將軍", "贺谔大将军"),
+    ("皮時大將軍", "皮时大将军"),
+    ("李誠大將軍", "李诚大将军"),
+    ("吳遂大將軍", "吴遂大将军"),
+    ("文折大將軍", "文折大将军"),
+    ("繆丙大將軍", "缪丙大将军"),
+    ("徐浩大將軍", "徐浩大将军"),
+    ("程寶大將軍", "程宝大将军"),
+    ("倪秘大將軍", "倪秘大将军"),
+    ("葉堅大將軍", "叶坚大将军"),
+    ("丘德大將軍", "丘德大将军"),
+    ("朱得大將軍", "朱得大将军"),
+    ("張朝大將軍", "张朝大将军"),
+    ("萬清大將軍", "万清大将军"),
+    ("辛亞大將軍", "辛亚大将军"),
+    ("易彥大將軍", "易彦大将军"),
+    ("黎卿大將軍", "黎卿大将军"),
+    ("傅黨大將軍", "傅党大将军"),
+    ("毛梓大將軍", "毛梓大将军"),
+    ("石政大將軍", "石政大将军"),
+    ("洪充大將軍", "洪充大将军"),
+    ("虞程大將軍", "虞程大将军"),
+];
@@ -0,0 +1,83 @@
+use core::iter::FusedIterator;
+
+use super::{TaiSuiConflict, TaiSuiConflicts};
+use crate::{EarthlyBranch, Zodiac};
+
+/// 所犯的太歲類別的迭代器。
+#[derive(Debug, Clone)]
+pub struct TaiSuiConflictsIter {
+    pub(super) bits: u8,
+}
+
+impl Iterator for TaiSuiConflictsIter {
+    type Item = TaiSuiConflict;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bits == 0 {
+            return None;
+        }
+
+        let i = self.bits.trailing_zeros() as u8;
+
+        self.bits &= self.bits - 1;
+
+        Some(unsafe { TaiSuiConflict::from_ordinal_unsafe(i + 1) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.bits.count_ones() as usize;
+
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for TaiSuiConflictsIter {}
+
+impl FusedIterator for TaiSuiConflictsIter {}
+
+impl IntoIterator for TaiSuiConflicts {
+    type IntoIter = TaiSuiConflictsIter;
+    type Item = TaiSuiConflict;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// 犯某年太歲的生肖的迭代器。
+#[derive(Debug, Clone)]
+pub struct ConflictingZodiacs {
+    pub(super) year:  EarthlyBranch,
+    pub(super) index: u8,
+}
+
+impl Iterator for ConflictingZodiacs {
+    type Item = (Zodiac, TaiSuiConflicts);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < 12 {
+            self.index += 1;
+
+            let zodiac = unsafe { Zodiac::from_ordinal_unsafe(self.index) };
+
+            let conflicts = TaiSuiConflicts::new(zodiac.to_earthly_branch(), self.year);
+
+            if !conflicts.is_empty() {
+                return Some((zodiac, conflicts));
+            }
+        }
+
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some((12 - self.index) as usize))
+    }
+}
+
+impl FusedIterator for ConflictingZodiacs {}
@@ -0,0 +1,351 @@
+mod chinese;
+mod iter;
+
+use core::fmt::{self, Display, Formatter};
+
+use chinese::{THE_TAI_SUI_CONFLICTS, THE_TAI_SUI_NAMES};
+use enum_ordinalize::Ordinalize;
+pub use iter::*;
+
+use crate::{ChineseVariant, EarthlyBranch, LunarYear, LunisolarYear, Zodiac};
+
+/// 列舉犯太歲的類別：本命年(值太歲)、沖太歲、刑太歲、害太歲、破太歲。
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash, Ordinalize)]
+#[ordinalize(impl_trait = false)]
+#[ordinalize(from_ordinal_unsafe(
+    pub fn from_ordinal_unsafe,
+    doc = "透過整數來取得 `TaiSuiConflict` 列舉實體。",
+    doc = "# Safety",
+    doc = "必須先確認傳入的整數是合法的。",
+))]
+#[ordinalize(ordinal(pub fn ordinal, doc = "取得 `TaiSuiConflict` 列舉實體所代表的整數。"))]
+#[repr(u8)]
+pub enum TaiSuiConflict {
+    /// 本命年(值太歲)，出生年的地支和流年地支相同。
+    Birth = 1,
+    /// 沖太歲，出生年的地支和流年地支六沖。
+    Clash,
+    /// 刑太歲，出生年的地支和流年地支相刑。
+    Punishment,
+    /// 害太歲，出生年的地支和流年地支相害。
+    Harm,
+    /// 破太歲，出生年的地支和流年地支相破。
+    Break,
+}
+
+impl Display for TaiSuiConflict {
+    /// Formats the value using the given formatter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chinese_lunisolar_calendar::TaiSuiConflict;
+    /// assert_eq!("沖太歲", format!("{}", TaiSuiConflict::Clash));
+    /// assert_eq!("冲太岁", format!("{:#}", TaiSuiConflict::Clash));
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str(self.to_str(ChineseVariant::Simple))
+        } else {
+            f.write_str(self.to_str(ChineseVariant::Traditional))
+        }
+    }
+}
+
+impl TaiSuiConflict {
+    /// 取得 `TaiSuiConflict` 列舉實體所代表的字串。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{ChineseVariant, TaiSuiConflict};
+    ///
+    /// assert_eq!(
+    ///     "本命年",
+    ///     TaiSuiConflict::Birth.to_str(ChineseVariant::Traditional)
+    /// );
+    /// assert_eq!(
+    ///     "刑太岁",
+    ///     TaiSuiConflict::Punishment.to_str(ChineseVariant::Simple)
+    /// );
+    /// ```
+    #[inline]
+    pub const fn to_str(self, chinese_variant: ChineseVariant) -> &'static str {
+        let i = (self.ordinal() - 1) as usize;
+
+        match chinese_variant {
+            ChineseVariant::Simple => THE_TAI_SUI_CONFLICTS[i].1,
+            ChineseVariant::Traditional => THE_TAI_SUI_CONFLICTS[i].0,
+        }
+    }
+}
+
+/// 出生年的地支在某個流年所犯的所有太歲類別。一個地支可能同時犯多種太歲，例如辰年出生的人在辰年既是本命年也是刑太歲(辰辰自刑)。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct TaiSuiConflicts(u8);
+
+impl Display for TaiSuiConflicts {
+    /// Formats the value using the given formatter. 多個類別之間以頓號分隔。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{LunisolarYear, SolarYear, Zodiac};
+    ///
+    /// let lunisolar_year =
+    ///     LunisolarYear::from_solar_year(SolarYear::from_u16(2024)).unwrap();
+    ///
+    /// let conflicts = Zodiac::Dragon.tai_sui_conflicts(lunisolar_year);
+    ///
+    /// assert_eq!("本命年、刑太歲", format!("{}", conflicts));
+    /// assert_eq!("本命年、刑太岁", format!("{:#}", conflicts));
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, conflict) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str("、")?;
+            }
+
+            Display::fmt(&conflict, f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl TaiSuiConflicts {
+    /// 計算出生年的地支 `birth` 在流年地支 `year` 所犯的太歲類別。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{
+    ///     EarthlyBranch, TaiSuiConflict, TaiSuiConflicts,
+    /// };
+    ///
+    /// // 寅年出生的人在巳年既刑太歲也害太歲
+    /// let conflicts =
+    ///     TaiSuiConflicts::new(EarthlyBranch::Third, EarthlyBranch::Sixth);
+    ///
+    /// assert!(conflicts.contains(TaiSuiConflict::Punishment));
+    /// assert!(conflicts.contains(TaiSuiConflict::Harm));
+    /// assert!(!conflicts.contains(TaiSuiConflict::Clash));
+    /// ```
+    #[inline]
+    pub const fn new(birth: EarthlyBranch, year: EarthlyBranch) -> Self {
+        let mut bits = 0;
+
+        if birth as u8 == year as u8 {
+            bits |= 1 << (TaiSuiConflict::Birth as u8 - 1);
+        }
+
+        if birth.clashes_with(year) {
+            bits |= 1 << (TaiSuiConflict::Clash as u8 - 1);
+        }
+
+        if birth.punishes(year) || year.punishes(birth) {
+            bits |= 1 << (TaiSuiConflict::Punishment as u8 - 1);
+        }
+
+        if birth.harms(year) {
+            bits |= 1 << (TaiSuiConflict::Harm as u8 - 1);
+        }
+
+        if birth.breaks(year) {
+            bits |= 1 << (TaiSuiConflict::Break as u8 - 1);
+        }
+
+        TaiSuiConflicts(bits)
+    }
+
+    /// 判斷是否包含某個犯太歲的類別。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{
+    ///     EarthlyBranch, TaiSuiConflict, TaiSuiConflicts,
+    /// };
+    ///
+    /// // 子年生的人遇到午年
+    /// let conflicts =
+    ///     TaiSuiConflicts::new(EarthlyBranch::First, EarthlyBranch::Seventh);
+    ///
+    /// assert!(conflicts.contains(TaiSuiConflict::Clash));
+    /// assert!(!conflicts.contains(TaiSuiConflict::Birth));
+    /// ```
+    #[inline]
+    pub const fn contains(self, conflict: TaiSuiConflict) -> bool {
+        self.0 & (1 << (conflict as u8 - 1)) != 0
+    }
+
+    /// 判斷是否沒有犯太歲。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{EarthlyBranch, TaiSuiConflicts};
+    ///
+    /// assert!(
+    ///     TaiSuiConflicts::new(EarthlyBranch::First, EarthlyBranch::Second)
+    ///         .is_empty()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// 取得所犯的太歲類別的數量。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{EarthlyBranch, TaiSuiConflicts};
+    ///
+    /// // 午年生的人遇到午年，為本命年，午又自刑
+    /// assert_eq!(
+    ///     2,
+    ///     TaiSuiConflicts::new(EarthlyBranch::Seventh, EarthlyBranch::Seventh)
+    ///         .len()
+    /// );
+    /// assert_eq!(
+    ///     0,
+    ///     TaiSuiConflicts::new(EarthlyBranch::First, EarthlyBranch::Second).len()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// 依照本命年、沖太歲、刑太歲、害太歲、破太歲的順序，迭代所犯的太歲類別。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{
+    ///     EarthlyBranch, TaiSuiConflict, TaiSuiConflicts,
+    /// };
+    ///
+    /// let mut iter =
+    ///     TaiSuiConflicts::new(EarthlyBranch::Seventh, EarthlyBranch::Seventh)
+    ///         .iter();
+    ///
+    /// assert_eq!(Some(TaiSuiConflict::Birth), iter.next());
+    /// assert_eq!(Some(TaiSuiConflict::Punishment), iter.next());
+    /// assert_eq!(None, iter.next());
+    /// ```
+    #[inline]
+    pub const fn iter(self) -> TaiSuiConflictsIter {
+        TaiSuiConflictsIter {
+            bits: self.0
+        }
+    }
+}
+
+impl EarthlyBranch {
+    /// 以此地支為出生年的地支，計算在流年 `lunisolar_year` 所犯的太歲類別。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{
+    ///     EarthlyBranch, LunisolarYear, SolarYear, TaiSuiConflict,
+    /// };
+    ///
+    /// let lunisolar_year =
+    ///     LunisolarYear::from_solar_year(SolarYear::from_u16(2024)).unwrap();
+    ///
+    /// assert!(
+    ///     EarthlyBranch::Eleventh
+    ///         .tai_sui_conflicts(lunisolar_year)
+    ///         .contains(TaiSuiConflict::Clash)
+    /// );
+    /// ```
+    #[inline]
+    pub const fn tai_sui_conflicts(self, lunisolar_year: LunisolarYear) -> TaiSuiConflicts {
+        TaiSuiConflicts::new(self, lunisolar_year.to_earthly_branch())
+    }
+}
+
+impl Zodiac {
+    /// 以此生肖為出生年的生肖，計算在流年 `lunisolar_year` 所犯的太歲類別。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{
+    ///     LunisolarYear, SolarYear, TaiSuiConflict, Zodiac,
+    /// };
+    ///
+    /// let lunisolar_year =
+    ///     LunisolarYear::from_solar_year(SolarYear::from_u16(2024)).unwrap();
+    ///
+    /// assert!(
+    ///     Zodiac::Rabbit
+    ///         .tai_sui_conflicts(lunisolar_year)
+    ///         .contains(TaiSuiConflict::Harm)
+    /// );
+    /// assert!(Zodiac::Rat.tai_sui_conflicts(lunisolar_year).is_empty());
+    /// ```
+    #[inline]
+    pub const fn tai_sui_conflicts(self, lunisolar_year: LunisolarYear) -> TaiSuiConflicts {
+        self.to_earthly_branch().tai_sui_conflicts(lunisolar_year)
+    }
+}
+
+impl LunisolarYear {
+    /// 依照鼠、牛、虎、...、豬的順序，列出所有犯此年太歲的生肖及其所犯的太歲類別。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{LunisolarYear, SolarYear, Zodiac};
+    ///
+    /// let lunisolar_year =
+    ///     LunisolarYear::from_solar_year(SolarYear::from_u16(2024)).unwrap();
+    ///
+    /// let zodiacs = lunisolar_year
+    ///     .conflicting_zodiacs()
+    ///     .map(|(zodiac, _)| zodiac)
+    ///     .collect::<Vec<Zodiac>>();
+    ///
+    /// assert_eq!(
+    ///     vec![Zodiac::Ox, Zodiac::Rabbit, Zodiac::Dragon, Zodiac::Dog],
+    ///     zodiacs
+    /// );
+    /// ```
+    #[inline]
+    pub const fn conflicting_zodiacs(self) -> ConflictingZodiacs {
+        ConflictingZodiacs {
+            year: self.to_earthly_branch(), index: 0
+        }
+    }
+}
+
+impl LunarYear {
+    /// 取得此年值年的太歲星君名號，採用民間廟宇常見的六十太歲名錄(甲子年為金辨大將軍)。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{ChineseVariant, LunarYear};
+    ///
+    /// let lunar_year = LunarYear::parse_str("甲辰").unwrap();
+    ///
+    /// assert_eq!("李誠大將軍", lunar_year.tai_sui_name(ChineseVariant::Traditional));
+    /// assert_eq!("李诚大将军", lunar_year.tai_sui_name(ChineseVariant::Simple));
+    /// ```
+    #[inline]
+    pub const fn tai_sui_name(self, chinese_variant: ChineseVariant) -> &'static str {
+        let i = (self.to_sexagenary().ordinal() - 1) as usize;
+
+        match chinese_variant {
+            ChineseVariant::Simple => THE_TAI_SUI_NAMES[i].1,
+            ChineseVariant::Traditional => THE_TAI_SUI_NAMES[i].0,
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use core::str::FromStr;
+
+use super::TenGods;
+
+impl FromStr for TenGods {
+    type Err = ();
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_str(s).ok_or(())
+    }
+}
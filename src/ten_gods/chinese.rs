@@ -0,0 +1,13 @@
+/// 列舉十神：比肩、劫財、食神、傷官、偏財、正財、七殺、正官、偏印、正印。
+pub(super) const THE_TEN_GODS: [(&str, &str); 10] = [
+    ("比肩", "比肩"),
+    ("劫財", "劫财"),
+    ("食神", "食神"),
+    ("傷官", "伤官"),
+    ("偏財", "偏财"),
+    ("正財", "正财"),
+    ("七殺", "七杀"),
+    ("正官", "正官"),
+    ("偏印", "偏印"),
+    ("正印", "正印"),
+];
@@ -0,0 +1,195 @@
+mod built_in_traits;
+mod chinese;
+mod parse;
+
+use core::fmt::{self, Display, Formatter};
+
+use chinese::THE_TEN_GODS;
+use enum_ordinalize::Ordinalize;
+
+use crate::{ChineseVariant, EarthlyBranch, HeavenlyStems, HiddenStems};
+
+/// 列舉十神，表示天干與日主(日干)之間的生剋關係。
+///
+/// 同我者為比劫，我生者為食傷，我剋者為財，剋我者為官殺，生我者為印。陰陽相同者為偏(比肩、食神、偏財、七殺、偏印)，陰陽相異者為正(劫財、傷官、正財、正官、正印)。
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash, Ordinalize)]
+#[ordinalize(impl_trait = false)]
+#[ordinalize(from_ordinal_unsafe(
+    pub fn from_ordinal_unsafe,
+    doc = "透過整數來取得 `TenGods` 列舉實體。",
+    doc = "# Safety",
+    doc = "必須先確認傳入的整數是合法的。",
+))]
+#[ordinalize(ordinal(pub fn ordinal, doc = "取得 `TenGods` 列舉實體所代表的整數。"))]
+#[repr(u8)]
+pub enum TenGods {
+    /// 比肩
+    Companion = 1,
+    /// 劫財
+    RobWealth,
+    /// 食神
+    EatingGod,
+    /// 傷官
+    HurtingOfficer,
+    /// 偏財
+    IndirectWealth,
+    /// 正財
+    DirectWealth,
+    /// 七殺
+    SevenKillings,
+    /// 正官
+    DirectOfficer,
+    /// 偏印
+    IndirectResource,
+    /// 正印
+    DirectResource,
+}
+
+impl Display for TenGods {
+    /// Formats the value using the given formatter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chinese_lunisolar_calendar::TenGods;
+    /// assert_eq!("七殺", format!("{}", TenGods::SevenKillings));
+    /// assert_eq!("七杀", format!("{:#}", TenGods::SevenKillings));
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str(self.to_str(ChineseVariant::Simple))
+        } else {
+            f.write_str(self.to_str(ChineseVariant::Traditional))
+        }
+    }
+}
+
+/// 用以建立 `TenGods` 列舉實體的關聯函數。
+impl TenGods {
+    /// 計算天干 `heavenly_stems` 相對於日主 `day_master` 的十神。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{HeavenlyStems, TenGods};
+    ///
+    /// // 甲木日主見庚金，陽剋陽為七殺
+    /// assert_eq!(
+    ///     TenGods::SevenKillings,
+    ///     TenGods::from_stems(HeavenlyStems::First, HeavenlyStems::Seventh)
+    /// );
+    ///
+    /// // 甲木日主見辛金，陰剋陽為正官
+    /// assert_eq!(
+    ///     TenGods::DirectOfficer,
+    ///     TenGods::from_stems(HeavenlyStems::First, HeavenlyStems::Eighth)
+    /// );
+    /// ```
+    #[inline]
+    pub const fn from_stems(day_master: HeavenlyStems, heavenly_stems: HeavenlyStems) -> Self {
+        let d = day_master.ordinal() - 1;
+        let s = heavenly_stems.ordinal() - 1;
+
+        // 天干每兩個一組，依照木、火、土、金、水的相生順序排列，因此五行的差距即為生剋關係
+        let relation = (s / 2 + 5 - d / 2) % 5;
+        let opposite = (s & 1) != (d & 1);
+
+        unsafe { Self::from_ordinal_unsafe(relation * 2 + opposite as u8 + 1) }
+    }
+}
+
+/// 將 `TenGods` 列舉實體轉成其它型別的方法。
+impl TenGods {
+    /// 取得 `TenGods` 列舉實體所代表的十神字串。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{ChineseVariant, TenGods};
+    ///
+    /// assert_eq!("傷官", TenGods::HurtingOfficer.to_str(ChineseVariant::Traditional));
+    /// assert_eq!("伤官", TenGods::HurtingOfficer.to_str(ChineseVariant::Simple));
+    /// ```
+    #[inline]
+    pub const fn to_str(self, chinese_variant: ChineseVariant) -> &'static str {
+        let i = (self.ordinal() - 1) as usize;
+
+        match chinese_variant {
+            ChineseVariant::Simple => THE_TEN_GODS[i].1,
+            ChineseVariant::Traditional => THE_TEN_GODS[i].0,
+        }
+    }
+}
+
+impl HeavenlyStems {
+    /// 計算此天干相對於日主 `day_master` 的十神。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{HeavenlyStems, TenGods};
+    ///
+    /// assert_eq!(
+    ///     TenGods::DirectResource,
+    ///     HeavenlyStems::Tenth.to_ten_gods(HeavenlyStems::First)
+    /// );
+    /// ```
+    #[inline]
+    pub const fn to_ten_gods(self, day_master: HeavenlyStems) -> TenGods {
+        TenGods::from_stems(day_master, self)
+    }
+}
+
+impl HiddenStems {
+    /// 計算各藏干相對於日主 `day_master` 的十神，依序為本氣、中氣、餘氣。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{EarthlyBranch, HeavenlyStems, TenGods};
+    ///
+    /// // 甲木日主見寅，藏干甲、丙、戊
+    /// assert_eq!(
+    ///     [
+    ///         Some(TenGods::Companion),
+    ///         Some(TenGods::EatingGod),
+    ///         Some(TenGods::IndirectWealth)
+    ///     ],
+    ///     EarthlyBranch::Third.hidden_stems().to_ten_gods(HeavenlyStems::First)
+    /// );
+    /// ```
+    #[inline]
+    pub const fn to_ten_gods(self, day_master: HeavenlyStems) -> [Option<TenGods>; 3] {
+        let middle = match self.middle_qi() {
+            Some(heavenly_stems) => Some(heavenly_stems.to_ten_gods(day_master)),
+            None => None,
+        };
+
+        let residual = match self.residual_qi() {
+            Some(heavenly_stems) => Some(heavenly_stems.to_ten_gods(day_master)),
+            None => None,
+        };
+
+        [Some(self.main_qi().to_ten_gods(day_master)), middle, residual]
+    }
+}
+
+impl EarthlyBranch {
+    /// 以本氣計算此地支相對於日主 `day_master` 的十神。若需要中氣和餘氣的十神，請使用 `hidden_stems().to_ten_gods(day_master)`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{EarthlyBranch, HeavenlyStems, TenGods};
+    ///
+    /// assert_eq!(
+    ///     TenGods::DirectResource,
+    ///     EarthlyBranch::First.to_ten_gods(HeavenlyStems::First)
+    /// );
+    /// ```
+    #[inline]
+    pub const fn to_ten_gods(self, day_master: HeavenlyStems) -> TenGods {
+        self.hidden_stems().main_qi().to_ten_gods(day_master)
+    }
+}
@@ -0,0 +1,26 @@
+use super::{THE_TEN_GODS, TenGods};
+
+/// 用以解析字串的關聯函數。
+impl TenGods {
+    /// 透過比肩、劫財、食神、傷官、偏財、正財、七殺、正官、偏印、正印等字串來取得 `TenGods` 列舉實體。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chinese_lunisolar_calendar::TenGods;
+    /// assert_eq!(TenGods::DirectWealth, TenGods::parse_str("正財").unwrap());
+    /// assert_eq!(TenGods::DirectWealth, TenGods::parse_str("正财").unwrap());
+    /// ```
+    #[inline]
+    pub fn parse_str<S: AsRef<str>>(s: S) -> Option<Self> {
+        let s = s.as_ref();
+
+        for (i, t) in THE_TEN_GODS.iter().enumerate() {
+            if s == t.0 || s == t.1 {
+                return Some(unsafe { Self::from_ordinal_unsafe(i as u8 + 1) });
+            }
+        }
+
+        None
+    }
+}
@@ -0,0 +1,28 @@
+use core::str::FromStr;
+
+use chrono::Weekday;
+
+use super::ChineseWeekday;
+
+impl From<Weekday> for ChineseWeekday {
+    #[inline]
+    fn from(value: Weekday) -> Self {
+        Self::from_weekday(value)
+    }
+}
+
+impl From<ChineseWeekday> for Weekday {
+    #[inline]
+    fn from(value: ChineseWeekday) -> Self {
+        value.to_weekday()
+    }
+}
+
+impl FromStr for ChineseWeekday {
+    type Err = ();
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_str(s).ok_or(())
+    }
+}
@@ -0,0 +1,33 @@
+/// 列舉星期一到星期日的中文寫法，依序為「星期」、「週」、「禮拜」三種前綴。
+pub(super) const THE_WEEKDAYS: [[(&str, &str); 7]; 3] = [
+    [
+        ("星期一", "星期一"),
+        ("星期二", "星期二"),
+        ("星期三", "星期三"),
+        ("星期四", "星期四"),
+        ("星期五", "星期五"),
+        ("星期六", "星期六"),
+        ("星期日", "星期日"),
+    ],
+    [
+        ("週一", "周一"),
+        ("週二", "周二"),
+        ("週三", "周三"),
+        ("週四", "周四"),
+        ("週五", "周五"),
+        ("週六", "周六"),
+        ("週日", "周日"),
+    ],
+    [
+        ("禮拜一", "礼拜一"),
+        ("禮拜二", "礼拜二"),
+        ("禮拜三", "礼拜三"),
+        ("禮拜四", "礼拜四"),
+        ("禮拜五", "礼拜五"),
+        ("禮拜六", "礼拜六"),
+        ("禮拜日", "礼拜日"),
+    ],
+];
+
+/// 星期日的口語寫法：星期天、禮拜天。
+pub(super) const THE_SUNDAY_ALIASES: [&str; 3] = ["星期天", "禮拜天", "礼拜天"];
@@ -0,0 +1,168 @@
+mod built_in_traits;
+mod chinese;
+mod parse;
+
+use core::fmt::{self, Display, Formatter};
+
+use chinese::THE_WEEKDAYS;
+use chrono::Weekday;
+use enum_ordinalize::Ordinalize;
+
+use crate::ChineseVariant;
+
+/// 中文星期的寫法。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum WeekdayStyle {
+    /// 星期一
+    #[default]
+    XingQi,
+    /// 週一、周一
+    Zhou,
+    /// 禮拜一、礼拜一
+    LiBai,
+}
+
+/// 列舉星期一到星期日，可以和 `chrono::Weekday` 互相轉換，並輸出成中文。
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash, Ordinalize)]
+#[ordinalize(impl_trait = false)]
+#[ordinalize(from_ordinal_unsafe(
+    pub fn from_ordinal_unsafe,
+    doc = "透過整數來取得 `ChineseWeekday` 列舉實體。",
+    doc = "# Safety",
+    doc = "必須先確認傳入的整數是合法的。",
+))]
+#[ordinalize(ordinal(pub fn ordinal, doc = "取得 `ChineseWeekday` 列舉實體所代表的整數。"))]
+#[repr(u8)]
+pub enum ChineseWeekday {
+    /// 星期一
+    Monday = 1,
+    /// 星期二
+    Tuesday,
+    /// 星期三
+    Wednesday,
+    /// 星期四
+    Thursday,
+    /// 星期五
+    Friday,
+    /// 星期六
+    Saturday,
+    /// 星期日
+    Sunday,
+}
+
+impl Display for ChineseWeekday {
+    /// Formats the value using the given formatter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chinese_lunisolar_calendar::ChineseWeekday;
+    /// assert_eq!("星期一", format!("{}", ChineseWeekday::Monday));
+    /// assert_eq!("星期一", format!("{:#}", ChineseWeekday::Monday));
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str(self.to_str(WeekdayStyle::XingQi, ChineseVariant::Simple))
+        } else {
+            f.write_str(self.to_str(WeekdayStyle::XingQi, ChineseVariant::Traditional))
+        }
+    }
+}
+
+/// 用以建立 `ChineseWeekday` 列舉實體的關聯函數。
+impl ChineseWeekday {
+    /// 透過 `chrono::Weekday` 來取得 `ChineseWeekday` 列舉實體。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{ChineseWeekday, chrono::Weekday};
+    ///
+    /// assert_eq!(
+    ///     ChineseWeekday::Monday,
+    ///     ChineseWeekday::from_weekday(Weekday::Mon)
+    /// );
+    /// ```
+    #[inline]
+    pub const fn from_weekday(weekday: Weekday) -> Self {
+        match weekday {
+            Weekday::Mon => Self::Monday,
+            Weekday::Tue => Self::Tuesday,
+            Weekday::Wed => Self::Wednesday,
+            Weekday::Thu => Self::Thursday,
+            Weekday::Fri => Self::Friday,
+            Weekday::Sat => Self::Saturday,
+            Weekday::Sun => Self::Sunday,
+        }
+    }
+}
+
+/// 將 `ChineseWeekday` 列舉實體轉成其它型別的方法。
+impl ChineseWeekday {
+    /// 取得 `ChineseWeekday` 列舉實體所代表的中文字串。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{
+    ///     ChineseVariant, ChineseWeekday, WeekdayStyle,
+    /// };
+    ///
+    /// let weekday = ChineseWeekday::Monday;
+    ///
+    /// assert_eq!(
+    ///     "星期一",
+    ///     weekday.to_str(WeekdayStyle::XingQi, ChineseVariant::Traditional)
+    /// );
+    /// assert_eq!(
+    ///     "週一",
+    ///     weekday.to_str(WeekdayStyle::Zhou, ChineseVariant::Traditional)
+    /// );
+    /// assert_eq!(
+    ///     "周一",
+    ///     weekday.to_str(WeekdayStyle::Zhou, ChineseVariant::Simple)
+    /// );
+    /// assert_eq!(
+    ///     "禮拜一",
+    ///     weekday.to_str(WeekdayStyle::LiBai, ChineseVariant::Traditional)
+    /// );
+    /// ```
+    #[inline]
+    pub const fn to_str(
+        self,
+        style: WeekdayStyle,
+        chinese_variant: ChineseVariant,
+    ) -> &'static str {
+        let i = (self.ordinal() - 1) as usize;
+
+        let t = THE_WEEKDAYS[style as usize][i];
+
+        match chinese_variant {
+            ChineseVariant::Simple => t.1,
+            ChineseVariant::Traditional => t.0,
+        }
+    }
+
+    /// 轉成 `chrono::Weekday`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{ChineseWeekday, chrono::Weekday};
+    ///
+    /// assert_eq!(Weekday::Sun, ChineseWeekday::Sunday.to_weekday());
+    /// ```
+    #[inline]
+    pub const fn to_weekday(self) -> Weekday {
+        match self {
+            Self::Monday => Weekday::Mon,
+            Self::Tuesday => Weekday::Tue,
+            Self::Wednesday => Weekday::Wed,
+            Self::Thursday => Weekday::Thu,
+            Self::Friday => Weekday::Fri,
+            Self::Saturday => Weekday::Sat,
+            Self::Sunday => Weekday::Sun,
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use super::{
+    ChineseWeekday,
+    chinese::{THE_SUNDAY_ALIASES, THE_WEEKDAYS},
+};
+
+/// 用以解析字串的關聯函數。
+impl ChineseWeekday {
+    /// 透過星期一、週一、周一、禮拜一、礼拜一等字串來取得 `ChineseWeekday` 列舉實體。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chinese_lunisolar_calendar::ChineseWeekday;
+    /// assert_eq!(
+    ///     ChineseWeekday::Monday,
+    ///     ChineseWeekday::parse_str("週一").unwrap()
+    /// );
+    /// assert_eq!(
+    ///     ChineseWeekday::Monday,
+    ///     ChineseWeekday::parse_str("周一").unwrap()
+    /// );
+    /// assert_eq!(
+    ///     ChineseWeekday::Sunday,
+    ///     ChineseWeekday::parse_str("禮拜天").unwrap()
+    /// );
+    /// ```
+    #[inline]
+    pub fn parse_str<S: AsRef<str>>(s: S) -> Option<Self> {
+        let s = s.as_ref();
+
+        for weekdays in THE_WEEKDAYS.iter() {
+            for (i, t) in weekdays.iter().enumerate() {
+                if s == t.0 || s == t.1 {
+                    return Some(unsafe { Self::from_ordinal_unsafe(i as u8 + 1) });
+                }
+            }
+        }
+
+        if THE_SUNDAY_ALIASES.contains(&s) {
+            return Some(Self::Sunday);
+        }
+
+        None
+    }
+}
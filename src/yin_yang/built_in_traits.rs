@@ -0,0 +1,21 @@
+use core::str::FromStr;
+
+use super::YinYang;
+
+impl TryFrom<char> for YinYang {
+    type Error = ();
+
+    #[inline]
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Self::from_char(value).ok_or(())
+    }
+}
+
+impl FromStr for YinYang {
+    type Err = ();
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_str(s).ok_or(())
+    }
+}
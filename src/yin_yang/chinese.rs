@@ -0,0 +1,5 @@
+/// 列舉陰陽：陽、陰。
+pub(super) const THE_YIN_YANG: [(&str, &str); 2] = [("陽", "阳"), ("陰", "阴")];
+
+/// 列舉陰陽：陽、陰。
+pub(super) const THE_YIN_YANG_CHAR: [(char, char); 2] = [('陽', '阳'), ('陰', '阴')];
@@ -0,0 +1,126 @@
+mod built_in_traits;
+mod chinese;
+mod parse;
+
+use core::fmt::{self, Display, Formatter};
+
+use chinese::{THE_YIN_YANG, THE_YIN_YANG_CHAR};
+use enum_ordinalize::Ordinalize;
+
+use crate::ChineseVariant;
+
+/// 列舉陰陽：陽、陰。
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash, Ordinalize)]
+#[ordinalize(impl_trait = false)]
+#[ordinalize(from_ordinal_unsafe(
+    pub fn from_ordinal_unsafe,
+    doc = "透過整數來取得 `YinYang` 列舉實體。",
+    doc = "# Safety",
+    doc = "必須先確認傳入的整數是合法的。",
+))]
+#[ordinalize(ordinal(pub fn ordinal, doc = "取得 `YinYang` 列舉實體所代表的整數。"))]
+#[repr(u8)]
+pub enum YinYang {
+    /// 陽
+    Yang = 1,
+    /// 陰
+    Yin,
+}
+
+impl Display for YinYang {
+    /// Formats the value using the given formatter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chinese_lunisolar_calendar::YinYang;
+    /// assert_eq!("陽", format!("{}", YinYang::Yang));
+    /// assert_eq!("阳", format!("{:#}", YinYang::Yang));
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str(self.to_str(ChineseVariant::Simple))
+        } else {
+            f.write_str(self.to_str(ChineseVariant::Traditional))
+        }
+    }
+}
+
+/// 用以建立 `YinYang` 列舉實體的關聯函數。
+impl YinYang {
+    /// 透過陽、陰等字元來取得 `YinYang` 列舉實體。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chinese_lunisolar_calendar::YinYang;
+    /// assert_eq!(YinYang::Yin, YinYang::from_char('陰').unwrap());
+    /// assert_eq!(YinYang::Yin, YinYang::from_char('阴').unwrap());
+    /// ```
+    #[inline]
+    pub const fn from_char(c: char) -> Option<Self> {
+        let len = THE_YIN_YANG_CHAR.len();
+
+        let mut i = 0;
+
+        loop {
+            let t = THE_YIN_YANG_CHAR[i];
+
+            if c == t.0 || c == t.1 {
+                return Some(unsafe { Self::from_ordinal_unsafe(i as u8 + 1) });
+            }
+
+            i += 1;
+
+            if i == len {
+                break;
+            }
+        }
+
+        None
+    }
+}
+
+/// 將 `YinYang` 列舉實體轉成其它型別的方法。
+impl YinYang {
+    /// 取得 `YinYang` 列舉實體所代表的陰陽字串。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{ChineseVariant, YinYang};
+    ///
+    /// assert_eq!("陽", YinYang::Yang.to_str(ChineseVariant::Traditional));
+    /// assert_eq!("阳", YinYang::Yang.to_str(ChineseVariant::Simple));
+    /// ```
+    #[inline]
+    pub const fn to_str(self, chinese_variant: ChineseVariant) -> &'static str {
+        let i = (self.ordinal() - 1) as usize;
+
+        match chinese_variant {
+            ChineseVariant::Simple => THE_YIN_YANG[i].1,
+            ChineseVariant::Traditional => THE_YIN_YANG[i].0,
+        }
+    }
+
+    /// 取得 `YinYang` 列舉實體所代表的陰陽字元。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{ChineseVariant, YinYang};
+    ///
+    /// assert_eq!('陰', YinYang::Yin.to_char(ChineseVariant::Traditional));
+    /// assert_eq!('阴', YinYang::Yin.to_char(ChineseVariant::Simple));
+    /// ```
+    #[inline]
+    pub const fn to_char(self, chinese_variant: ChineseVariant) -> char {
+        let i = (self.ordinal() - 1) as usize;
+
+        match chinese_variant {
+            ChineseVariant::Simple => THE_YIN_YANG_CHAR[i].1,
+            ChineseVariant::Traditional => THE_YIN_YANG_CHAR[i].0,
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use super::{THE_YIN_YANG, YinYang};
+
+/// 用以解析字串的關聯函數。
+impl YinYang {
+    /// 透過陽、陰等字串來取得 `YinYang` 列舉實體。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chinese_lunisolar_calendar::YinYang;
+    /// assert_eq!(YinYang::Yang, YinYang::parse_str("陽").unwrap());
+    /// assert_eq!(YinYang::Yang, YinYang::parse_str("阳").unwrap());
+    /// ```
+    #[inline]
+    pub fn parse_str<S: AsRef<str>>(s: S) -> Option<Self> {
+        let s = s.as_ref();
+
+        for (i, t) in THE_YIN_YANG.iter().enumerate() {
+            if s == t.0 || s == t.1 {
+                return Some(unsafe { Self::from_ordinal_unsafe(i as u8 + 1) });
+            }
+        }
+
+        None
+    }
+}
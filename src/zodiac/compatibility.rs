@@ -0,0 +1,190 @@
+use core::fmt::{self, Display, Formatter};
+
+use super::Zodiac;
+use crate::{ChineseVariant, FiveElements};
+
+/// 兩個生肖之間的相配關係。若同時符合多種關係，依照六合、三合、六沖、相害、相刑的順序取第一個。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ZodiacCompatibility {
+    /// 六合，附帶合化的五行。
+    SixHarmony(FiveElements),
+    /// 三合，附帶合局的五行。
+    ThreeHarmony(FiveElements),
+    /// 六沖
+    SixClash,
+    /// 相害
+    Harm,
+    /// 相刑
+    Punishment,
+    /// 無沖無合
+    Neutral,
+}
+
+impl Display for ZodiacCompatibility {
+    /// Formats the value using the given formatter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::Zodiac;
+    ///
+    /// let compatibility = Zodiac::Rat.compatibility(Zodiac::Horse);
+    ///
+    /// assert_eq!("六沖", format!("{}", compatibility));
+    /// assert_eq!("六冲", format!("{:#}", compatibility));
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str(self.to_str(ChineseVariant::Simple))
+        } else {
+            f.write_str(self.to_str(ChineseVariant::Traditional))
+        }
+    }
+}
+
+impl ZodiacCompatibility {
+    /// 取得相配關係的中文描述。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{ChineseVariant, Zodiac};
+    ///
+    /// let compatibility = Zodiac::Dog.compatibility(Zodiac::Rooster);
+    ///
+    /// assert_eq!("相害", compatibility.to_str(ChineseVariant::Traditional));
+    ///
+    /// let compatibility = Zodiac::Dog.compatibility(Zodiac::Rat);
+    ///
+    /// assert_eq!("無沖無合", compatibility.to_str(ChineseVariant::Traditional));
+    /// assert_eq!("无冲无合", compatibility.to_str(ChineseVariant::Simple));
+    /// ```
+    #[inline]
+    pub const fn to_str(self, chinese_variant: ChineseVariant) -> &'static str {
+        match chinese_variant {
+            ChineseVariant::Simple => match self {
+                Self::SixHarmony(_) => "六合",
+                Self::ThreeHarmony(_) => "三合",
+                Self::SixClash => "六冲",
+                Self::Harm => "相害",
+                Self::Punishment => "相刑",
+                Self::Neutral => "无冲无合",
+            },
+            ChineseVariant::Traditional => match self {
+                Self::SixHarmony(_) => "六合",
+                Self::ThreeHarmony(_) => "三合",
+                Self::SixClash => "六沖",
+                Self::Harm => "相害",
+                Self::Punishment => "相刑",
+                Self::Neutral => "無沖無合",
+            },
+        }
+    }
+
+    /// 取得六合或三合的五行，其它關係回傳 `None`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{FiveElements, Zodiac};
+    ///
+    /// assert_eq!(
+    ///     Some(FiveElements::Fire),
+    ///     Zodiac::Tiger.compatibility(Zodiac::Horse).to_five_elements()
+    /// );
+    /// assert_eq!(
+    ///     None,
+    ///     Zodiac::Tiger.compatibility(Zodiac::Monkey).to_five_elements()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn to_five_elements(self) -> Option<FiveElements> {
+        match self {
+            Self::SixHarmony(five_elements) | Self::ThreeHarmony(five_elements) => {
+                Some(five_elements)
+            },
+            _ => None,
+        }
+    }
+
+    /// 判斷是否為相合的關係(六合或三合)。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::Zodiac;
+    ///
+    /// assert!(Zodiac::Rat.compatibility(Zodiac::Ox).is_harmonious());
+    /// assert!(Zodiac::Rat.compatibility(Zodiac::Monkey).is_harmonious());
+    /// assert!(!Zodiac::Rat.compatibility(Zodiac::Horse).is_harmonious());
+    /// ```
+    #[inline]
+    pub const fn is_harmonious(self) -> bool {
+        matches!(self, Self::SixHarmony(_) | Self::ThreeHarmony(_))
+    }
+
+    /// 判斷是否為相沖、相害或相刑的關係。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::Zodiac;
+    ///
+    /// assert!(Zodiac::Rat.compatibility(Zodiac::Horse).is_conflicting());
+    /// assert!(Zodiac::Rat.compatibility(Zodiac::Goat).is_conflicting());
+    /// assert!(!Zodiac::Rat.compatibility(Zodiac::Dog).is_conflicting());
+    /// ```
+    #[inline]
+    pub const fn is_conflicting(self) -> bool {
+        matches!(self, Self::SixClash | Self::Harm | Self::Punishment)
+    }
+}
+
+impl Zodiac {
+    /// 以生肖對應的地支，判斷兩個生肖之間的相配關係。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chinese_lunisolar_calendar::{
+    ///     FiveElements, Zodiac, ZodiacCompatibility,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     ZodiacCompatibility::SixHarmony(FiveElements::Earth),
+    ///     Zodiac::Rat.compatibility(Zodiac::Ox)
+    /// );
+    /// assert_eq!(
+    ///     ZodiacCompatibility::ThreeHarmony(FiveElements::Water),
+    ///     Zodiac::Rat.compatibility(Zodiac::Dragon)
+    /// );
+    /// assert_eq!(
+    ///     ZodiacCompatibility::Punishment,
+    ///     Zodiac::Rat.compatibility(Zodiac::Rabbit)
+    /// );
+    /// ```
+    #[inline]
+    pub const fn compatibility(self, other: Zodiac) -> ZodiacCompatibility {
+        let a = self.to_earthly_branch();
+        let b = other.to_earthly_branch();
+
+        if let Some(five_elements) = a.combines_with(b) {
+            return ZodiacCompatibility::SixHarmony(five_elements);
+        }
+
+        if let Some(five_elements) = a.three_harmony_with(b) {
+            return ZodiacCompatibility::ThreeHarmony(five_elements);
+        }
+
+        if a.clashes_with(b) {
+            ZodiacCompatibility::SixClash
+        } else if a.harms(b) {
+            ZodiacCompatibility::Harm
+        } else if a.punishes(b) || b.punishes(a) {
+            ZodiacCompatibility::Punishment
+        } else {
+            ZodiacCompatibility::Neutral
+        }
+    }
+}
@@ -1,5 +1,6 @@
 mod built_in_traits;
 mod chinese;
+mod compatibility;
 mod parse;
 
 use core::{
@@ -8,6 +9,7 @@ use core::{
 };
 
 use chinese::{THE_ZODIAC_SIGNS, THE_ZODIAC_SIGNS_CHAR};
+pub use compatibility::*;
 use enum_ordinalize::Ordinalize;
 
 use crate::{ChineseVariant, EarthlyBranch};
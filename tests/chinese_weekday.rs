@@ -0,0 +1,46 @@
+use chinese_lunisolar_calendar::{ChineseVariant, ChineseWeekday, WeekdayStyle, chrono::Weekday};
+
+#[test]
+fn parse_str() {
+    assert_eq!(ChineseWeekday::Monday, ChineseWeekday::parse_str("星期一").unwrap());
+    assert_eq!(ChineseWeekday::Wednesday, ChineseWeekday::parse_str("週三").unwrap());
+    assert_eq!(ChineseWeekday::Wednesday, ChineseWeekday::parse_str("周三").unwrap());
+    assert_eq!(ChineseWeekday::Friday, ChineseWeekday::parse_str("禮拜五").unwrap());
+    assert_eq!(ChineseWeekday::Friday, ChineseWeekday::parse_str("礼拜五").unwrap());
+    assert_eq!(ChineseWeekday::Sunday, ChineseWeekday::parse_str("星期日").unwrap());
+    assert_eq!(ChineseWeekday::Sunday, ChineseWeekday::parse_str("星期天").unwrap());
+    assert_eq!(ChineseWeekday::Sunday, ChineseWeekday::parse_str("礼拜天").unwrap());
+    assert!(ChineseWeekday::parse_str("週天").is_none());
+}
+
+#[test]
+fn to_str() {
+    let weekday = ChineseWeekday::Sunday;
+
+    assert_eq!("星期日", weekday.to_str(WeekdayStyle::XingQi, ChineseVariant::Traditional));
+    assert_eq!("星期日", weekday.to_str(WeekdayStyle::XingQi, ChineseVariant::Simple));
+    assert_eq!("週日", weekday.to_str(WeekdayStyle::Zhou, ChineseVariant::Traditional));
+    assert_eq!("周日", weekday.to_str(WeekdayStyle::Zhou, ChineseVariant::Simple));
+    assert_eq!("禮拜日", weekday.to_str(WeekdayStyle::LiBai, ChineseVariant::Traditional));
+    assert_eq!("礼拜日", weekday.to_str(WeekdayStyle::LiBai, ChineseVariant::Simple));
+}
+
+#[test]
+fn weekday() {
+    let weekdays = [
+        Weekday::Mon,
+        Weekday::Tue,
+        Weekday::Wed,
+        Weekday::Thu,
+        Weekday::Fri,
+        Weekday::Sat,
+        Weekday::Sun,
+    ];
+
+    for (i, weekday) in weekdays.into_iter().enumerate() {
+        let chinese_weekday = ChineseWeekday::from(weekday);
+
+        assert_eq!(i as u8 + 1, chinese_weekday.ordinal());
+        assert_eq!(weekday, Weekday::from(chinese_weekday));
+    }
+}
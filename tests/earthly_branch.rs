@@ -1,4 +1,6 @@
-use chinese_lunisolar_calendar::{EarthlyBranch, Zodiac, chrono::prelude::*};
+use chinese_lunisolar_calendar::{
+    EarthlyBranch, FiveElements, HeavenlyStems, YinYang, Zodiac, chrono::prelude::*,
+};
 
 #[test]
 fn parse_str() {
@@ -67,3 +69,210 @@ fn to_zodiac() {
     assert_eq!(Zodiac::Dragon, EarthlyBranch::Fifth.to_zodiac());
     assert_eq!(Zodiac::Pig, EarthlyBranch::Twelfth.to_zodiac());
 }
+
+#[test]
+fn to_five_elements() {
+    let expected = [
+        FiveElements::Water,
+        FiveElements::Earth,
+        FiveElements::Wood,
+        FiveElements::Wood,
+        FiveElements::Earth,
+        FiveElements::Fire,
+        FiveElements::Fire,
+        FiveElements::Earth,
+        FiveElements::Metal,
+        FiveElements::Metal,
+        FiveElements::Earth,
+        FiveElements::Water,
+    ];
+
+    for (c, five_elements) in "子丑寅卯辰巳午未申酉戌亥".chars().zip(expected) {
+        assert_eq!(five_elements, EarthlyBranch::from_char(c).unwrap().to_five_elements());
+    }
+}
+
+#[test]
+fn to_yin_yang() {
+    assert_eq!(YinYang::Yang, EarthlyBranch::First.to_yin_yang());
+    assert_eq!(YinYang::Yin, EarthlyBranch::Second.to_yin_yang());
+    assert_eq!(YinYang::Yang, EarthlyBranch::Eleventh.to_yin_yang());
+    assert_eq!(YinYang::Yin, EarthlyBranch::Twelfth.to_yin_yang());
+}
+
+#[test]
+fn hidden_stems() {
+    let expected = [
+        "癸",
+        "己癸辛",
+        "甲丙戊",
+        "乙",
+        "戊乙癸",
+        "丙庚戊",
+        "丁己",
+        "己丁乙",
+        "庚壬戊",
+        "辛",
+        "戊辛丁",
+        "壬甲",
+    ];
+
+    for (c, expected) in "子丑寅卯辰巳午未申酉戌亥".chars().zip(expected) {
+        let hidden_stems = EarthlyBranch::from_char(c).unwrap().hidden_stems();
+
+        let stems = hidden_stems.as_slice();
+
+        assert!(stems.iter().map(|stem| stem.to_char()).eq(expected.chars()), "{c}");
+        assert_eq!(stems.len(), hidden_stems.conventional_weights().len());
+        assert_eq!(100u8, hidden_stems.conventional_weights().iter().sum());
+        assert!(hidden_stems.conventional_weights().windows(2).all(|w| w[0] > w[1]));
+
+        // 本氣的五行和地支相同
+        assert_eq!(
+            EarthlyBranch::from_char(c).unwrap().to_five_elements(),
+            hidden_stems.main_qi().to_five_elements()
+        );
+    }
+
+    let hidden_stems = EarthlyBranch::Twelfth.hidden_stems();
+
+    assert_eq!(HeavenlyStems::Ninth, hidden_stems.main_qi());
+    assert_eq!(Some(HeavenlyStems::First), hidden_stems.middle_qi());
+    assert_eq!(None, hidden_stems.residual_qi());
+}
+
+#[test]
+fn ruling_stems() {
+    let expected = [
+        ("壬癸", [10, 20].as_slice()),
+        ("癸辛己", &[9, 3, 18]),
+        ("戊丙甲", &[7, 7, 16]),
+        ("甲乙", &[10, 20]),
+        ("乙癸戊", &[9, 3, 18]),
+        ("戊庚丙", &[5, 9, 16]),
+        ("丙己丁", &[10, 9, 11]),
+        ("丁乙己", &[9, 3, 18]),
+        ("戊壬庚", &[7, 7, 16]),
+        ("庚辛", &[10, 20]),
+        ("辛丁戊", &[9, 3, 18]),
+        ("戊甲壬", &[7, 5, 18]),
+    ];
+
+    for (c, (expected_stems, expected_days)) in "子丑寅卯辰巳午未申酉戌亥".chars().zip(expected)
+    {
+        let earthly_branch = EarthlyBranch::from_char(c).unwrap();
+        let ruling_stems = earthly_branch.ruling_stems();
+
+        let stems = ruling_stems.as_slice();
+
+        assert!(stems.iter().map(|stem| stem.to_char()).eq(expected_stems.chars()), "{c}");
+        assert_eq!(expected_days, ruling_stems.days(), "{c}");
+        assert_eq!(30u8, ruling_stems.days().iter().sum());
+
+        // 最後司令的天干即為本氣
+        assert_eq!(earthly_branch.hidden_stems().main_qi(), *stems.last().unwrap());
+
+        for (stem, days) in stems.iter().zip(ruling_stems.days()) {
+            assert_eq!(*days, ruling_stems.days_of(*stem));
+        }
+    }
+
+    assert_eq!(0, EarthlyBranch::First.ruling_stems().days_of(HeavenlyStems::First));
+}
+
+#[test]
+fn relations() {
+    let branch = |c| EarthlyBranch::from_char(c).unwrap();
+    let pairs = |s: &str| {
+        let chars = s.chars().collect::<Vec<_>>();
+
+        chars.chunks(2).map(|c| (branch(c[0]), branch(c[1]))).collect::<Vec<_>>()
+    };
+    let all = || "子丑寅卯辰巳午未申酉戌亥".chars().map(branch);
+
+    let combinations = pairs("子丑寅亥卯戌辰酉巳申午未");
+    let elements = "土木火金水土".chars().map(|c| FiveElements::from_char(c).unwrap());
+
+    for ((a, b), five_elements) in combinations.iter().copied().zip(elements) {
+        assert_eq!(Some(five_elements), a.combines_with(b));
+        assert_eq!(Some(five_elements), b.combines_with(a));
+    }
+
+    let clashes = pairs("子午丑未寅申卯酉辰戌巳亥");
+    let harms = pairs("子未丑午寅巳卯辰申亥酉戌");
+    let breaks = pairs("子酉寅亥辰丑午卯申巳戌未");
+
+    for a in all() {
+        for b in all() {
+            assert_eq!(
+                combinations.contains(&(a, b)) || combinations.contains(&(b, a)),
+                a.combines_with(b).is_some()
+            );
+            assert_eq!(clashes.contains(&(a, b)) || clashes.contains(&(b, a)), a.clashes_with(b));
+            assert_eq!(harms.contains(&(a, b)) || harms.contains(&(b, a)), a.harms(b));
+            assert_eq!(breaks.contains(&(a, b)) || breaks.contains(&(b, a)), a.breaks(b));
+        }
+    }
+
+    let three_harmonies = ["申子辰", "巳酉丑", "寅午戌", "亥卯未"];
+    let elements = || "水金火木".chars().map(|c| FiveElements::from_char(c).unwrap());
+
+    for a in all() {
+        for b in all() {
+            let expected = three_harmonies
+                .iter()
+                .zip(elements())
+                .find(|(s, _)| a != b && s.contains(a.to_char()) && s.contains(b.to_char()))
+                .map(|(_, five_elements)| five_elements);
+
+            assert_eq!(expected, a.three_harmony_with(b));
+        }
+    }
+
+    let punishments = pairs("寅巳巳申申寅丑戌戌未未丑子卯卯子辰辰午午酉酉亥亥");
+
+    for a in all() {
+        for b in all() {
+            assert_eq!(punishments.contains(&(a, b)), a.punishes(b), "{a}{b}");
+        }
+    }
+}
+
+#[test]
+fn find_three_harmony_and_meeting() {
+    let branches =
+        |s: &str| s.chars().map(|c| EarthlyBranch::from_char(c).unwrap()).collect::<Vec<_>>();
+
+    assert_eq!(Some(FiveElements::Water), EarthlyBranch::find_three_harmony(&branches("申子辰")));
+    assert_eq!(Some(FiveElements::Metal), EarthlyBranch::find_three_harmony(&branches("酉巳午丑")));
+    assert_eq!(Some(FiveElements::Fire), EarthlyBranch::find_three_harmony(&branches("戌寅午")));
+    assert_eq!(Some(FiveElements::Wood), EarthlyBranch::find_three_harmony(&branches("未亥卯卯")));
+    assert_eq!(None, EarthlyBranch::find_three_harmony(&branches("申子子辰辰")[..3]));
+    assert_eq!(None, EarthlyBranch::find_three_harmony(&branches("寅卯辰")));
+    assert_eq!(None, EarthlyBranch::find_three_harmony(&[]));
+    // 有多組時，依水局、金局、火局、木局的順序回傳
+    assert_eq!(
+        Some(FiveElements::Water),
+        EarthlyBranch::find_three_harmony(&branches("寅午戌申子辰"))
+    );
+    assert_eq!(
+        Some(FiveElements::Fire),
+        EarthlyBranch::find_three_harmony(&branches("亥卯未寅午戌"))
+    );
+
+    assert_eq!(Some(FiveElements::Wood), EarthlyBranch::find_three_meeting(&branches("寅卯辰")));
+    assert_eq!(Some(FiveElements::Fire), EarthlyBranch::find_three_meeting(&branches("未午子巳")));
+    assert_eq!(Some(FiveElements::Metal), EarthlyBranch::find_three_meeting(&branches("申酉戌")));
+    assert_eq!(Some(FiveElements::Water), EarthlyBranch::find_three_meeting(&branches("丑亥子")));
+    assert_eq!(None, EarthlyBranch::find_three_meeting(&branches("申子辰")));
+    assert_eq!(None, EarthlyBranch::find_three_meeting(&branches("亥子寅")));
+    // 有多組時，依木方、火方、金方、水方的順序回傳
+    assert_eq!(
+        Some(FiveElements::Wood),
+        EarthlyBranch::find_three_meeting(&branches("亥子丑寅卯辰"))
+    );
+    assert_eq!(
+        Some(FiveElements::Metal),
+        EarthlyBranch::find_three_meeting(&branches("亥子丑申酉戌"))
+    );
+}
@@ -0,0 +1,33 @@
+use chinese_lunisolar_calendar::{ChineseVariant, FiveElements};
+
+#[test]
+fn parse_str() {
+    assert_eq!(FiveElements::Wood, FiveElements::parse_str("木").unwrap());
+    assert_eq!(FiveElements::Fire, FiveElements::parse_str("火").unwrap());
+    assert_eq!(FiveElements::Earth, FiveElements::parse_str("土").unwrap());
+    assert_eq!(FiveElements::Metal, FiveElements::parse_str("金").unwrap());
+    assert_eq!(FiveElements::Water, FiveElements::parse_str("水").unwrap());
+    assert!(FiveElements::parse_str("風").is_none());
+}
+
+#[test]
+fn to_str() {
+    assert_eq!("木", FiveElements::Wood.to_str(ChineseVariant::Traditional));
+    assert_eq!("火", FiveElements::Fire.to_str(ChineseVariant::Simple));
+    assert_eq!("土", FiveElements::Earth.to_str(ChineseVariant::Traditional));
+    assert_eq!("金", FiveElements::Metal.to_str(ChineseVariant::Simple));
+    assert_eq!("水", FiveElements::Water.to_str(ChineseVariant::Traditional));
+}
+
+#[test]
+fn from_char() {
+    assert_eq!(FiveElements::Wood, FiveElements::from_char('木').unwrap());
+    assert_eq!(FiveElements::Water, FiveElements::from_char('水').unwrap());
+    assert!(FiveElements::from_char('風').is_none());
+}
+
+#[test]
+fn to_char() {
+    assert_eq!('木', FiveElements::Wood.to_char(ChineseVariant::Traditional));
+    assert_eq!('水', FiveElements::Water.to_char(ChineseVariant::Simple));
+}
@@ -0,0 +1,249 @@
+use chinese_lunisolar_calendar::{
+    FourPillars, FourPillarsOptions, LateZiHourRule, LunisolarDate,
+    MAX_LUNISOLAR_DATE_IN_SOLAR_DATE, MIN_LUNISOLAR_DATE_IN_SOLAR_DATE, MonthPillarMode,
+    Sexagenary, SolarDate,
+    chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, Timelike},
+};
+
+#[test]
+fn from_solar_date_time() {
+    let four_pillars = |y, m, d, h, options| {
+        FourPillars::from_solar_date_time(
+            SolarDate::from_ymd(y, m, d).unwrap(),
+            NaiveTime::from_hms_opt(h, 0, 0).unwrap(),
+            options,
+        )
+        .unwrap()
+        .to_string()
+    };
+
+    let default = FourPillarsOptions::default();
+    let lunar_month = FourPillarsOptions::new(MonthPillarMode::LunarMonth, LateZiHourRule::NextDay);
+    let current_day =
+        FourPillarsOptions::new(MonthPillarMode::SolarTerm, LateZiHourRule::CurrentDay);
+
+    assert_eq!("己丑年 癸酉月 甲子日 庚午時", four_pillars(1949, 10, 1, 12, default));
+    assert_eq!("己卯年 丙子月 戊午日 壬子時", four_pillars(2000, 1, 1, 0, default));
+    assert_eq!("甲辰年 丙寅月 甲辰日 甲子時", four_pillars(2024, 2, 10, 0, default));
+    assert_eq!("甲辰年 丙寅月 甲辰日 丙子時", four_pillars(2024, 2, 10, 23, current_day));
+    assert_eq!("甲辰年 丙寅月 乙巳日 丙子時", four_pillars(2024, 2, 10, 23, default));
+    // 晚子時屬於隔天時，除夕的晚子時仍屬於前一個農曆年和月份
+    assert_eq!("癸卯年 乙丑月 甲辰日 甲子時", four_pillars(2024, 2, 9, 23, lunar_month));
+    assert_eq!("甲辰年 丙寅月 甲辰日 甲子時", four_pillars(2024, 2, 10, 0, lunar_month));
+
+    // 2024-02-04 16:27 立春，2024-02-10 春節
+    assert_eq!("癸卯年 乙丑月 戊戌日 庚申時", four_pillars(2024, 2, 4, 16, default));
+    assert_eq!("甲辰年 丙寅月 戊戌日 辛酉時", four_pillars(2024, 2, 4, 17, default));
+    assert_eq!("癸卯年 乙丑月 戊戌日 庚申時", four_pillars(2024, 2, 4, 16, lunar_month));
+    assert_eq!("癸卯年 乙丑月 戊戌日 壬子時", four_pillars(2024, 2, 3, 23, default));
+    assert_eq!("癸卯年 乙丑月 丁酉日 壬子時", four_pillars(2024, 2, 3, 23, current_day));
+    assert_eq!("癸卯年 乙丑月 丁酉日 庚子時", four_pillars(2024, 2, 3, 0, current_day));
+
+    // 支援範圍的最後一天，晚子時屬於隔天時也能推算
+    assert_eq!("庚申年 己丑月 乙亥日 壬午時", four_pillars(2101, 1, 28, 12, default));
+    assert_eq!("庚申年 己丑月 丙子日 戊子時", four_pillars(2101, 1, 28, 23, default));
+    assert_eq!("庚申年 己丑月 乙亥日 戊子時", four_pillars(2101, 1, 28, 23, current_day));
+}
+
+#[test]
+fn from_lunisolar_date_time() {
+    let lunisolar_date = LunisolarDate::from_ymd(2008, 7, false, 8).unwrap();
+
+    let four_pillars = FourPillars::from_lunisolar_date_time(
+        lunisolar_date,
+        NaiveTime::from_hms_opt(20, 8, 0).unwrap(),
+        FourPillarsOptions::default(),
+    )
+    .unwrap();
+
+    assert_eq!("戊子", four_pillars.to_year_pillar().to_str());
+    assert_eq!("庚申", four_pillars.to_month_pillar().to_str());
+    assert_eq!("庚辰", four_pillars.to_day_pillar().to_str());
+    assert_eq!("丙戌", four_pillars.to_hour_pillar().to_str());
+    assert_eq!("戊子年 庚申月 庚辰日 丙戌時", four_pillars.to_string());
+    assert_eq!("戊子年 庚申月 庚辰日 丙戌时", format!("{four_pillars:#}"));
+
+    let four_pillars = FourPillars::from_lunisolar_date_time(
+        lunisolar_date,
+        NaiveTime::from_hms_opt(20, 8, 0).unwrap(),
+        FourPillarsOptions::new(MonthPillarMode::LunarMonth, LateZiHourRule::NextDay),
+    )
+    .unwrap();
+
+    assert_eq!("戊子年 庚申月 庚辰日 丙戌時", four_pillars.to_string());
+
+    let max = LunisolarDate::from_solar_date(MAX_LUNISOLAR_DATE_IN_SOLAR_DATE).unwrap();
+
+    assert!(
+        FourPillars::from_lunisolar_date_time(
+            max,
+            NaiveTime::from_hms_opt(23, 0, 0).unwrap(),
+            FourPillarsOptions::default(),
+        )
+        .is_ok()
+    );
+    assert!(
+        FourPillars::from_lunisolar_date_time(
+            max,
+            NaiveTime::from_hms_opt(23, 0, 0).unwrap(),
+            FourPillarsOptions::new(MonthPillarMode::SolarTerm, LateZiHourRule::CurrentDay),
+        )
+        .is_ok()
+    );
+}
+
+#[test]
+fn to_array() {
+    let pillars = ["甲辰", "丙寅", "甲辰", "甲子"].map(|s| Sexagenary::parse_str(s).unwrap());
+
+    let four_pillars = FourPillars::from(pillars);
+
+    assert_eq!(pillars, four_pillars.to_array());
+    assert_eq!(pillars, <[Sexagenary; 4]>::from(four_pillars));
+}
+
+#[test]
+fn parse_str() {
+    let four_pillars = FourPillars::parse_str("甲子 丙寅 戊辰 壬子").unwrap();
+
+    assert_eq!("甲子年 丙寅月 戊辰日 壬子時", four_pillars.to_string());
+    assert_eq!(four_pillars, "甲子年 丙寅月 戊辰日 壬子时".parse().unwrap());
+    assert_eq!(four_pillars, FourPillars::parse_str(four_pillars.to_string()).unwrap());
+
+    assert!(FourPillars::parse_str("甲子 丙寅 戊辰").is_err());
+    assert!(FourPillars::parse_str("甲子 丙寅 戊辰 壬子 壬子").is_err());
+    assert!(
+        FourPillars::parse_str("甲子 丙寅 戊辰 壬子")
+            .unwrap()
+            .search(FourPillarsOptions::default())
+            .next()
+            .is_some()
+    );
+    assert!(FourPillars::parse_str("甲子 丙寅 戊辰 壬丑").is_err());
+    assert!(FourPillars::parse_str("甲子月 丙寅 戊辰 壬子").is_err());
+}
+
+#[test]
+fn search() {
+    let options_list = [
+        FourPillarsOptions::default(),
+        FourPillarsOptions::new(MonthPillarMode::SolarTerm, LateZiHourRule::CurrentDay),
+        FourPillarsOptions::new(MonthPillarMode::LunarMonth, LateZiHourRule::NextDay),
+    ];
+
+    for options in options_list {
+        let date_times = [(1901, 2, 19, 0), (1990, 2, 20, 20), (2024, 2, 3, 23), (2101, 1, 28, 12)];
+
+        for (y, m, d, h) in date_times {
+            let four_pillars = FourPillars::from_solar_date_time(
+                SolarDate::from_ymd(y, m, d).unwrap(),
+                NaiveTime::from_hms_opt(h, 0, 0).unwrap(),
+                options,
+            )
+            .unwrap();
+
+            let s = four_pillars.to_string();
+
+            let mut hours = 0;
+
+            for four_pillars_match in four_pillars.search(options) {
+                let start = four_pillars_match.start();
+                let end = four_pillars_match.end();
+
+                assert!(start < end);
+
+                let four_pillars_at = |time: NaiveDateTime| {
+                    FourPillars::from_solar_date_time(
+                        SolarDate::from_date(time.date()).unwrap(),
+                        time.time(),
+                        options,
+                    )
+                    .unwrap()
+                };
+
+                assert_eq!(four_pillars, four_pillars_at(start));
+                assert_eq!(four_pillars, four_pillars_at(end - TimeDelta::minutes(1)));
+
+                // 交節時刻不一定是整點
+                let mut time = start.with_minute(0).unwrap();
+
+                if time < start {
+                    time += TimeDelta::hours(1);
+                }
+
+                while time < end {
+                    assert_eq!(four_pillars, four_pillars_at(time));
+
+                    time += TimeDelta::hours(1);
+                    hours += 1;
+                }
+            }
+
+            // 逐日逐小時檢查，確保沒有遺漏
+            let mut expected_hours = 0;
+
+            let mut date = MIN_LUNISOLAR_DATE_IN_SOLAR_DATE.to_naive_date();
+
+            while date <= MAX_LUNISOLAR_DATE_IN_SOLAR_DATE.to_naive_date() {
+                let solar_date = SolarDate::from_date(date).unwrap();
+
+                if solar_date.day_stem_branch() == four_pillars.to_day_pillar()
+                    || solar_date.day_stem_branch() + 1 == four_pillars.to_day_pillar()
+                {
+                    for hour in 0..24 {
+                        let time = NaiveTime::from_hms_opt(hour, 0, 0).unwrap();
+
+                        if let Ok(result) =
+                            FourPillars::from_solar_date_time(solar_date, time, options)
+                            && result == four_pillars
+                        {
+                            expected_hours += 1;
+                        }
+                    }
+                }
+
+                date = date.succ_opt().unwrap();
+            }
+
+            assert!(hours > 0, "{s}");
+            assert_eq!(expected_hours, hours, "{s}");
+        }
+    }
+
+    // 2024-02-04 16:27 立春，申時被切成兩段
+    let date_time =
+        |d, h, min| NaiveDate::from_ymd_opt(2024, 2, d).unwrap().and_hms_opt(h, min, 0).unwrap();
+
+    let four_pillars_match = FourPillars::parse_str("癸卯 乙丑 戊戌 庚申")
+        .unwrap()
+        .search(FourPillarsOptions::default())
+        .find(|four_pillars_match| four_pillars_match.start().year() == 2024)
+        .unwrap();
+
+    assert_eq!(date_time(4, 15, 0), four_pillars_match.start());
+    assert_eq!(date_time(4, 16, 27), four_pillars_match.end());
+
+    let four_pillars_match = FourPillars::parse_str("甲辰 丙寅 戊戌 庚申")
+        .unwrap()
+        .search(FourPillarsOptions::default())
+        .find(|four_pillars_match| four_pillars_match.start().year() == 2024)
+        .unwrap();
+
+    assert_eq!(date_time(4, 16, 27), four_pillars_match.start());
+    assert_eq!(date_time(4, 17, 0), four_pillars_match.end());
+
+    // 晚子時屬於隔天時，除夕的晚子時和春節的早子時分屬不同的年柱和月柱
+    let four_pillars_match = FourPillars::parse_str("癸卯 乙丑 甲辰 甲子")
+        .unwrap()
+        .search(FourPillarsOptions::new(MonthPillarMode::LunarMonth, LateZiHourRule::NextDay))
+        .find(|four_pillars_match| four_pillars_match.start().year() == 2024)
+        .unwrap();
+
+    assert_eq!(date_time(9, 23, 0), four_pillars_match.start());
+    assert_eq!(date_time(10, 0, 0), four_pillars_match.end());
+
+    // 時干和日干不相符
+    let four_pillars = FourPillars::parse_str("甲子 丙寅 戊辰 甲子").unwrap();
+
+    assert!(four_pillars.search(FourPillarsOptions::default()).next().is_none());
+}
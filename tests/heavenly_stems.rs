@@ -1,4 +1,4 @@
-use chinese_lunisolar_calendar::HeavenlyStems;
+use chinese_lunisolar_calendar::{FiveElements, HeavenlyStems, YinYang};
 
 #[test]
 fn parse_str() {
@@ -31,3 +31,65 @@ fn to_char() {
     assert_eq!('戊', HeavenlyStems::Fifth.to_char());
     assert_eq!('癸', HeavenlyStems::Tenth.to_char());
 }
+
+#[test]
+fn to_five_elements() {
+    let expected = [
+        FiveElements::Wood,
+        FiveElements::Wood,
+        FiveElements::Fire,
+        FiveElements::Fire,
+        FiveElements::Earth,
+        FiveElements::Earth,
+        FiveElements::Metal,
+        FiveElements::Metal,
+        FiveElements::Water,
+        FiveElements::Water,
+    ];
+
+    for (c, five_elements) in "甲乙丙丁戊己庚辛壬癸".chars().zip(expected) {
+        assert_eq!(five_elements, HeavenlyStems::from_char(c).unwrap().to_five_elements());
+    }
+}
+
+#[test]
+fn to_yin_yang() {
+    assert_eq!(YinYang::Yang, HeavenlyStems::First.to_yin_yang());
+    assert_eq!(YinYang::Yin, HeavenlyStems::Second.to_yin_yang());
+    assert_eq!(YinYang::Yang, HeavenlyStems::Ninth.to_yin_yang());
+    assert_eq!(YinYang::Yin, HeavenlyStems::Tenth.to_yin_yang());
+}
+
+#[test]
+fn relations() {
+    let stem = |c| HeavenlyStems::from_char(c).unwrap();
+    let all = || "甲乙丙丁戊己庚辛壬癸".chars().map(stem);
+
+    let combinations = [
+        ('甲', '己', '土'),
+        ('乙', '庚', '金'),
+        ('丙', '辛', '水'),
+        ('丁', '壬', '木'),
+        ('戊', '癸', '火'),
+    ];
+    let clashes = [('甲', '庚'), ('乙', '辛'), ('丙', '壬'), ('丁', '癸')];
+
+    for a in all() {
+        for b in all() {
+            let combination = combinations
+                .iter()
+                .find(|(x, y, _)| (stem(*x), stem(*y)) == (a, b) || (stem(*x), stem(*y)) == (b, a));
+
+            assert_eq!(
+                combination.map(|(_, _, e)| FiveElements::from_char(*e).unwrap()),
+                a.combines_with(b)
+            );
+
+            let clash = clashes
+                .iter()
+                .any(|(x, y)| (stem(*x), stem(*y)) == (a, b) || (stem(*x), stem(*y)) == (b, a));
+
+            assert_eq!(clash, a.clashes_with(b), "{a}{b}");
+        }
+    }
+}
@@ -1,5 +1,8 @@
 use chinese_lunisolar_calendar::{
-    LunarDay, LunarMonth, LunarYear, LunisolarDate, LunisolarYear, SolarDate, SolarYear,
+    LeapMonthPolicy, LunarArithmeticPolicy, LunarDay, LunarMonth, LunarOverflowPolicy, LunarYear,
+    LunisolarDate, LunisolarDateError, LunisolarYear, MAX_LUNISOLAR_DATE_IN_SOLAR_DATE,
+    MIN_LUNISOLAR_DATE_IN_SOLAR_DATE, MonthPillarMode, SolarDate, SolarYear,
+    chrono::{Days, NaiveTime, Weekday},
 };
 
 #[test]
@@ -170,3 +173,430 @@ fn the_n_day_in_this_year() {
     assert_eq!(200, LunisolarDate::from_ymd(1993, 6, false, 23).unwrap().the_n_day_in_this_year());
     assert_eq!(324, LunisolarDate::from_ymd(1993, 10, false, 29).unwrap().the_n_day_in_this_year());
 }
+
+#[test]
+fn checked_add_days() {
+    let lunisolar_date = LunisolarDate::from_ymd(1993, 3, false, 30).unwrap();
+
+    assert_eq!(lunisolar_date, lunisolar_date.checked_add_days(0).unwrap());
+    assert_eq!(
+        LunisolarDate::from_ymd(1993, 3, true, 1).unwrap(),
+        lunisolar_date.checked_add_days(1).unwrap()
+    );
+    assert_eq!(
+        LunisolarDate::from_ymd(1993, 4, false, 1).unwrap(),
+        lunisolar_date.checked_add_days(30).unwrap()
+    );
+    assert_eq!(
+        LunisolarDate::from_ymd(1994, 1, false, 1).unwrap(),
+        LunisolarDate::from_ymd(1993, 12, false, 29).unwrap().checked_add_days(1).unwrap()
+    );
+
+    let max = LunisolarDate::from_solar_date(MAX_LUNISOLAR_DATE_IN_SOLAR_DATE).unwrap();
+
+    assert!(max.checked_add_days(1).is_err());
+    assert!(lunisolar_date.checked_add_days(u32::MAX).is_err());
+}
+
+#[test]
+fn checked_sub_days() {
+    let lunisolar_date = LunisolarDate::from_ymd(1993, 3, true, 1).unwrap();
+
+    assert_eq!(lunisolar_date, lunisolar_date.checked_sub_days(0).unwrap());
+    assert_eq!(
+        LunisolarDate::from_ymd(1993, 3, false, 30).unwrap(),
+        lunisolar_date.checked_sub_days(1).unwrap()
+    );
+    assert_eq!(
+        LunisolarDate::from_ymd(1992, 12, false, 20).unwrap(),
+        LunisolarDate::from_ymd(1993, 1, false, 1).unwrap().checked_sub_days(11).unwrap()
+    );
+
+    let min = LunisolarDate::from_solar_date(MIN_LUNISOLAR_DATE_IN_SOLAR_DATE).unwrap();
+
+    assert!(min.checked_sub_days(1).is_err());
+    assert!(lunisolar_date.checked_sub_days(u32::MAX).is_err());
+}
+
+#[test]
+fn add_sub_days() {
+    let min = LunisolarDate::from_solar_date(MIN_LUNISOLAR_DATE_IN_SOLAR_DATE).unwrap();
+    let max = LunisolarDate::from_solar_date(MAX_LUNISOLAR_DATE_IN_SOLAR_DATE).unwrap();
+
+    let mut current = min;
+    let mut solar_date = MIN_LUNISOLAR_DATE_IN_SOLAR_DATE.to_naive_date();
+
+    while current != max {
+        let next = current + 1;
+
+        solar_date = solar_date.succ_opt().unwrap();
+
+        assert_eq!(solar_date, next.to_naive_date());
+        assert_eq!(current, next - 1);
+        assert_eq!(next, current.succ().unwrap());
+        assert_eq!(current, next.pred().unwrap());
+
+        current = next;
+    }
+
+    let mut lunisolar_date = min;
+
+    lunisolar_date += 73027;
+
+    assert_eq!(max, lunisolar_date);
+
+    lunisolar_date -= 73027;
+
+    assert_eq!(min, lunisolar_date);
+
+    lunisolar_date += Days::new(73027);
+
+    assert_eq!(max, lunisolar_date);
+
+    lunisolar_date -= Days::new(73027);
+
+    assert_eq!(min, lunisolar_date);
+    assert_eq!(min + 100, min + Days::new(100));
+    assert_eq!(max - 100, max - Days::new(100));
+
+    for days in (0..=73027).step_by(997) {
+        assert_eq!(min + days, min + Days::new(days as u64));
+        assert_eq!(max - days, max - Days::new(days as u64));
+    }
+}
+
+#[test]
+#[should_panic]
+fn add_days_out_of_range() {
+    let max = LunisolarDate::from_solar_date(MAX_LUNISOLAR_DATE_IN_SOLAR_DATE).unwrap();
+
+    let _ = max + Days::new(1);
+}
+
+#[test]
+#[should_panic]
+fn sub_days_out_of_range() {
+    let max = LunisolarDate::from_solar_date(MAX_LUNISOLAR_DATE_IN_SOLAR_DATE).unwrap();
+
+    let _ = max - Days::new(u64::MAX);
+}
+
+#[test]
+fn checked_add_lunar_months() {
+    let merge_clamp =
+        LunarArithmeticPolicy::new(LeapMonthPolicy::Merge, LunarOverflowPolicy::Clamp);
+    let merge_roll_over =
+        LunarArithmeticPolicy::new(LeapMonthPolicy::Merge, LunarOverflowPolicy::RollOver);
+    let merge_error =
+        LunarArithmeticPolicy::new(LeapMonthPolicy::Merge, LunarOverflowPolicy::Error);
+    let separate =
+        LunarArithmeticPolicy::new(LeapMonthPolicy::Separate, LunarOverflowPolicy::Error);
+
+    // 移動零個月一律回傳原日期，即使是閏月
+    let leap_month_date = LunisolarDate::from_ymd(2023, 2, true, 29).unwrap();
+
+    for policy in [merge_clamp, merge_roll_over, merge_error, separate] {
+        assert_eq!(leap_month_date, leap_month_date.checked_add_lunar_months(0, policy).unwrap());
+    }
+
+    let lunisolar_date = LunisolarDate::from_ymd(2023, 2, false, 30).unwrap();
+
+    assert_eq!(
+        LunisolarDate::from_ymd(2023, 3, false, 29).unwrap(),
+        lunisolar_date.checked_add_lunar_months(1, merge_clamp).unwrap()
+    );
+    assert_eq!(
+        LunisolarDate::from_ymd(2023, 4, false, 1).unwrap(),
+        lunisolar_date.checked_add_lunar_months(1, merge_roll_over).unwrap()
+    );
+    assert_eq!(
+        Err(LunisolarDateError::DayIncorrect),
+        lunisolar_date.checked_add_lunar_months(1, merge_error)
+    );
+    assert_eq!(
+        LunisolarDate::from_ymd(2024, 2, false, 30).unwrap(),
+        lunisolar_date.checked_add_lunar_months(12, merge_error).unwrap()
+    );
+
+    let lunisolar_date = LunisolarDate::from_ymd(2023, 2, true, 15).unwrap();
+
+    assert_eq!(
+        LunisolarDate::from_ymd(2023, 3, false, 15).unwrap(),
+        lunisolar_date.checked_add_lunar_months(1, merge_error).unwrap()
+    );
+    assert_eq!(
+        LunisolarDate::from_ymd(2023, 1, false, 15).unwrap(),
+        lunisolar_date.checked_add_lunar_months(-1, merge_error).unwrap()
+    );
+    assert_eq!(
+        LunisolarDate::from_ymd(2023, 2, false, 15).unwrap(),
+        lunisolar_date.checked_add_lunar_months(-1, separate).unwrap()
+    );
+    assert_eq!(
+        LunisolarDate::from_ymd(2023, 3, false, 15).unwrap(),
+        lunisolar_date.checked_add_lunar_months(1, separate).unwrap()
+    );
+
+    let lunisolar_date = LunisolarDate::from_ymd(2023, 1, false, 1).unwrap();
+
+    assert_eq!(
+        LunisolarDate::from_ymd(2023, 2, true, 1).unwrap(),
+        lunisolar_date.checked_add_lunar_months(2, separate).unwrap()
+    );
+    assert_eq!(
+        LunisolarDate::from_ymd(2024, 1, false, 1).unwrap(),
+        lunisolar_date.checked_add_lunar_months(13, separate).unwrap()
+    );
+    assert_eq!(
+        LunisolarDate::from_ymd(2023, 12, false, 1).unwrap(),
+        lunisolar_date.checked_add_lunar_months(12, separate).unwrap()
+    );
+    assert_eq!(
+        LunisolarDate::from_ymd(2022, 12, false, 1).unwrap(),
+        lunisolar_date.checked_add_lunar_months(-1, separate).unwrap()
+    );
+    assert_eq!(
+        LunisolarDate::from_ymd(2022, 1, false, 1).unwrap(),
+        lunisolar_date.checked_add_lunar_months(-12, separate).unwrap()
+    );
+
+    let lunisolar_date = LunisolarDate::from_ymd(2100, 12, false, 1).unwrap();
+
+    assert_eq!(
+        Err(LunisolarDateError::OutOfRange),
+        lunisolar_date.checked_add_lunar_months(1, merge_clamp)
+    );
+    assert_eq!(
+        Err(LunisolarDateError::OutOfRange),
+        lunisolar_date.checked_add_lunar_months(1, separate)
+    );
+
+    // 月份的計算不能溢位
+    for months in [i32::MAX, i32::MIN] {
+        for policy in [merge_clamp, separate] {
+            assert_eq!(
+                Err(LunisolarDateError::OutOfRange),
+                lunisolar_date.checked_add_lunar_months(months, policy)
+            );
+        }
+    }
+}
+
+#[test]
+fn checked_add_lunar_years() {
+    let merge = LunarArithmeticPolicy::new(LeapMonthPolicy::Merge, LunarOverflowPolicy::Error);
+    let separate_clamp =
+        LunarArithmeticPolicy::new(LeapMonthPolicy::Separate, LunarOverflowPolicy::Clamp);
+    let separate_roll_over =
+        LunarArithmeticPolicy::new(LeapMonthPolicy::Separate, LunarOverflowPolicy::RollOver);
+    let separate_error =
+        LunarArithmeticPolicy::new(LeapMonthPolicy::Separate, LunarOverflowPolicy::Error);
+
+    let lunisolar_date = LunisolarDate::from_ymd(2023, 2, true, 1).unwrap();
+
+    // 移動零年一律回傳原日期，即使是閏月
+    for policy in [merge, separate_clamp, separate_roll_over, separate_error] {
+        assert_eq!(lunisolar_date, lunisolar_date.checked_add_lunar_years(0, policy).unwrap());
+    }
+
+    assert_eq!(
+        LunisolarDate::from_ymd(2024, 2, false, 1).unwrap(),
+        lunisolar_date.checked_add_lunar_years(1, merge).unwrap()
+    );
+    assert_eq!(
+        LunisolarDate::from_ymd(2024, 2, false, 1).unwrap(),
+        lunisolar_date.checked_add_lunar_years(1, separate_clamp).unwrap()
+    );
+    assert_eq!(
+        LunisolarDate::from_ymd(2024, 3, false, 1).unwrap(),
+        lunisolar_date.checked_add_lunar_years(1, separate_roll_over).unwrap()
+    );
+    assert_eq!(
+        Err(LunisolarDateError::MonthIncorrect),
+        lunisolar_date.checked_add_lunar_years(1, separate_error)
+    );
+    assert_eq!(
+        LunisolarDate::from_ymd(2042, 2, true, 1).unwrap(),
+        lunisolar_date.checked_add_lunar_years(19, separate_error).unwrap()
+    );
+
+    let lunisolar_date = LunisolarDate::from_ymd(2023, 12, false, 30).unwrap();
+
+    assert_eq!(
+        LunisolarDate::from_ymd(2024, 12, false, 29).unwrap(),
+        lunisolar_date.checked_add_lunar_years(1, separate_clamp).unwrap()
+    );
+    assert_eq!(
+        LunisolarDate::from_ymd(2025, 1, false, 1).unwrap(),
+        lunisolar_date.checked_add_lunar_years(1, separate_roll_over).unwrap()
+    );
+    assert_eq!(
+        Err(LunisolarDateError::DayIncorrect),
+        lunisolar_date.checked_add_lunar_years(1, merge)
+    );
+    assert_eq!(
+        Err(LunisolarDateError::OutOfRange),
+        lunisolar_date.checked_add_lunar_years(100, merge)
+    );
+    assert_eq!(
+        Err(LunisolarDateError::OutOfRange),
+        lunisolar_date.checked_add_lunar_years(-123, merge)
+    );
+
+    // 年份的計算不能溢位
+    for years in [i32::MAX, i32::MIN] {
+        for policy in [merge, separate_clamp] {
+            assert_eq!(
+                Err(LunisolarDateError::OutOfRange),
+                lunisolar_date.checked_add_lunar_years(years, policy)
+            );
+        }
+    }
+}
+
+#[test]
+fn signed_days_since() {
+    let min = LunisolarDate::from_solar_date(MIN_LUNISOLAR_DATE_IN_SOLAR_DATE).unwrap();
+    let max = LunisolarDate::from_solar_date(MAX_LUNISOLAR_DATE_IN_SOLAR_DATE).unwrap();
+
+    assert_eq!(0, min.signed_days_since(min));
+    assert_eq!(73027, max.signed_days_since(min));
+    assert_eq!(-73027, min.signed_days_since(max));
+
+    let lunisolar_date_1 = LunisolarDate::from_ymd(1992, 12, false, 20).unwrap();
+    let lunisolar_date_2 = LunisolarDate::from_ymd(1993, 3, true, 1).unwrap();
+
+    assert_eq!(
+        (lunisolar_date_2.to_naive_date() - lunisolar_date_1.to_naive_date()).num_days() as i32,
+        lunisolar_date_2 - lunisolar_date_1
+    );
+    assert_eq!(
+        (lunisolar_date_1.to_naive_date() - lunisolar_date_2.to_naive_date()).num_days() as i32,
+        lunisolar_date_1 - lunisolar_date_2
+    );
+    assert_eq!(lunisolar_date_2, lunisolar_date_1 + (lunisolar_date_2 - lunisolar_date_1) as u32);
+}
+
+#[test]
+fn cmp() {
+    let lunisolar_date_1 = LunisolarDate::from_ymd(1992, 12, false, 20).unwrap();
+    let lunisolar_date_2 = LunisolarDate::from_ymd(1993, 1, false, 1).unwrap();
+    let lunisolar_date_3 = LunisolarDate::from_ymd(1993, 3, false, 30).unwrap();
+    let lunisolar_date_4 = LunisolarDate::from_ymd(1993, 3, true, 1).unwrap();
+
+    assert!(lunisolar_date_1 < lunisolar_date_2);
+    assert!(lunisolar_date_2 < lunisolar_date_3);
+    assert!(lunisolar_date_3 < lunisolar_date_4);
+    assert!(lunisolar_date_4 > lunisolar_date_1);
+}
+
+#[test]
+fn julian_day_number() {
+    let min = LunisolarDate::from_solar_date(MIN_LUNISOLAR_DATE_IN_SOLAR_DATE).unwrap();
+    let max = LunisolarDate::from_solar_date(MAX_LUNISOLAR_DATE_IN_SOLAR_DATE).unwrap();
+
+    assert_eq!(2415435, min.to_julian_day_number());
+    assert_eq!(min, LunisolarDate::from_julian_day_number(2415435).unwrap());
+    assert!(LunisolarDate::from_julian_day_number(2415434).is_err());
+    assert!(LunisolarDate::from_rata_die(max.to_rata_die() + 1).is_err());
+
+    let mut lunisolar_date = min;
+
+    loop {
+        let rata_die = lunisolar_date.to_rata_die();
+
+        assert_eq!(lunisolar_date.to_solar_date().to_rata_die(), rata_die);
+        assert_eq!(lunisolar_date, LunisolarDate::from_rata_die(rata_die).unwrap());
+        assert_eq!(
+            lunisolar_date,
+            LunisolarDate::from_julian_day_number(lunisolar_date.to_julian_day_number()).unwrap()
+        );
+
+        match lunisolar_date.succ() {
+            Ok(next) => {
+                assert_eq!(rata_die + 1, next.to_rata_die());
+
+                lunisolar_date = next;
+            },
+            Err(_) => break,
+        }
+    }
+
+    assert_eq!(max, lunisolar_date);
+}
+
+#[test]
+fn from_ymd_on_the_last_solar_day() {
+    let lunisolar_date = LunisolarDate::from_ymd(1901, 11, false, 21).unwrap();
+
+    assert_eq!(SolarYear::from_u16(1901), lunisolar_date.to_solar_year());
+    assert_eq!(SolarDate::from_ymd(1901, 12, 31).unwrap(), lunisolar_date.to_solar_date());
+    assert_eq!(
+        lunisolar_date,
+        SolarDate::from_ymd(1901, 12, 31).unwrap().to_lunisolar_date().unwrap()
+    );
+}
+
+#[test]
+fn weekday() {
+    assert_eq!(Weekday::Sat, LunisolarDate::from_ymd(2024, 1, false, 1).unwrap().weekday());
+    assert_eq!(Weekday::Fri, LunisolarDate::from_ymd(2023, 12, false, 30).unwrap().weekday());
+    assert_eq!(Weekday::Tue, LunisolarDate::from_ymd(1901, 1, false, 1).unwrap().weekday());
+}
+
+#[test]
+fn day_stem_branch() {
+    let lunisolar_date = LunisolarDate::from_solar_date(MIN_LUNISOLAR_DATE_IN_SOLAR_DATE).unwrap();
+    assert_eq!("戊辰", lunisolar_date.day_stem_branch().to_str());
+
+    let lunisolar_date = LunisolarDate::from_solar_date(MAX_LUNISOLAR_DATE_IN_SOLAR_DATE).unwrap();
+    assert_eq!("乙亥", lunisolar_date.day_stem_branch().to_str());
+
+    let lunisolar_date = LunisolarDate::from_ymd(1949, 8, false, 10).unwrap();
+    assert_eq!("甲子", lunisolar_date.day_stem_branch().to_str());
+
+    let lunisolar_date = LunisolarDate::from_ymd(2008, 7, false, 8).unwrap();
+    assert_eq!("庚辰", lunisolar_date.day_stem_branch().to_str());
+}
+
+#[test]
+fn month_stem_branch() {
+    let noon = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+
+    let month_stem_branch = |y, m, leap, d, mode| {
+        LunisolarDate::from_ymd(y, m, leap, d).unwrap().month_stem_branch(noon, mode).to_string()
+    };
+
+    assert_eq!("甲寅", month_stem_branch(2023, 1, false, 1, MonthPillarMode::LunarMonth));
+    assert_eq!("乙卯", month_stem_branch(2023, 2, false, 1, MonthPillarMode::LunarMonth));
+    assert_eq!("乙卯", month_stem_branch(2023, 2, true, 29, MonthPillarMode::LunarMonth));
+    assert_eq!("丙辰", month_stem_branch(2023, 3, false, 1, MonthPillarMode::LunarMonth));
+    assert_eq!("乙丑", month_stem_branch(2023, 12, false, 30, MonthPillarMode::LunarMonth));
+    assert_eq!("丙寅", month_stem_branch(2024, 1, false, 1, MonthPillarMode::LunarMonth));
+    assert_eq!("庚子", month_stem_branch(1901, 11, false, 1, MonthPillarMode::LunarMonth));
+
+    // 2023-03-22 是閏二月初一，已過 2023-03-06 驚蟄
+    assert_eq!("乙卯", month_stem_branch(2023, 2, true, 1, MonthPillarMode::SolarTerm));
+    // 2023-04-05 09:13 清明
+    assert_eq!("丙辰", month_stem_branch(2023, 2, true, 15, MonthPillarMode::SolarTerm));
+    assert_eq!(
+        "乙卯",
+        LunisolarDate::from_ymd(2023, 2, true, 15)
+            .unwrap()
+            .month_stem_branch(
+                NaiveTime::from_hms_opt(9, 12, 0).unwrap(),
+                MonthPillarMode::SolarTerm
+            )
+            .to_str()
+    );
+    assert_eq!("丙寅", month_stem_branch(2024, 1, false, 1, MonthPillarMode::SolarTerm));
+    // 2024-02-04 16:27 立春
+    assert_eq!("丙寅", month_stem_branch(2023, 12, false, 26, MonthPillarMode::SolarTerm));
+    assert_eq!("乙丑", month_stem_branch(2023, 12, false, 25, MonthPillarMode::SolarTerm));
+
+    let min = LunisolarDate::from_solar_date(MIN_LUNISOLAR_DATE_IN_SOLAR_DATE).unwrap();
+    let max = LunisolarDate::from_solar_date(MAX_LUNISOLAR_DATE_IN_SOLAR_DATE).unwrap();
+
+    assert_eq!("庚寅", min.month_stem_branch(noon, MonthPillarMode::SolarTerm).to_str());
+    assert_eq!("己丑", max.month_stem_branch(noon, MonthPillarMode::SolarTerm).to_str());
+}
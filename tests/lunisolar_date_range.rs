@@ -0,0 +1,67 @@
+use chinese_lunisolar_calendar::{
+    LunisolarDate, LunisolarDateRange, LunisolarMonth, LunisolarYear, SolarDate,
+};
+
+#[test]
+fn new() {
+    let lunisolar_date_1 = LunisolarDate::from_ymd(1993, 12, false, 1).unwrap();
+    let lunisolar_date_2 = LunisolarDate::from_ymd(1994, 1, false, 15).unwrap();
+
+    assert!(lunisolar_date_1 < lunisolar_date_2);
+    assert!(LunisolarDateRange::new(lunisolar_date_1, lunisolar_date_2).is_some());
+    assert!(LunisolarDateRange::new(lunisolar_date_2, lunisolar_date_1).is_none());
+    assert!(LunisolarDateRange::new(lunisolar_date_1, lunisolar_date_1).is_some());
+}
+
+#[test]
+fn contains() {
+    let range = LunisolarDateRange::new(
+        LunisolarDate::from_ymd(1993, 3, false, 15).unwrap(),
+        LunisolarDate::from_ymd(1993, 4, false, 15).unwrap(),
+    )
+    .unwrap();
+
+    assert!(range.contains(LunisolarDate::from_ymd(1993, 3, false, 15).unwrap()));
+    assert!(range.contains(LunisolarDate::from_ymd(1993, 3, true, 1).unwrap()));
+    assert!(range.contains(LunisolarDate::from_ymd(1993, 4, false, 15).unwrap()));
+    assert!(!range.contains(LunisolarDate::from_ymd(1993, 3, false, 14).unwrap()));
+    assert!(!range.contains(LunisolarDate::from_ymd(1993, 4, false, 16).unwrap()));
+    assert!(!range.contains(LunisolarDate::from_ymd(1992, 3, false, 20).unwrap()));
+}
+
+#[test]
+fn intersection() {
+    let range_1 = LunisolarMonth::from_ym(1993, 3, true).unwrap().range();
+    let range_2 = LunisolarMonth::from_ym(1993, 4, false).unwrap().range();
+    let range_3 = LunisolarYear::from_solar_year(1993.into()).unwrap().range();
+
+    assert_eq!(None, range_1.intersection(range_2));
+    assert_eq!(Some(range_1), range_1.intersection(range_3));
+    assert_eq!(Some(range_2), range_3.intersection(range_2));
+}
+
+#[test]
+fn iter() {
+    let range = LunisolarDateRange::new(
+        LunisolarDate::from_ymd(1992, 12, false, 20).unwrap(),
+        LunisolarDate::from_ymd(1993, 3, true, 1).unwrap(),
+    )
+    .unwrap();
+
+    let dates: Vec<LunisolarDate> = range.into_iter().collect();
+
+    assert_eq!(range.len_days() as usize, dates.len());
+    assert_eq!(range.start(), dates[0]);
+    assert_eq!(range.end(), *dates.last().unwrap());
+    assert!(dates.windows(2).all(|w| w[0].succ().unwrap() == w[1]));
+
+    let reversed: Vec<LunisolarDate> = range.iter().rev().collect();
+
+    assert_eq!(dates.into_iter().rev().collect::<Vec<_>>(), reversed);
+
+    let solar_date_range = range.to_solar_date_range();
+
+    assert_eq!(SolarDate::from_ymd(1993, 1, 12).unwrap(), solar_date_range.start());
+    assert_eq!(SolarDate::from_ymd(1993, 4, 22).unwrap(), solar_date_range.end());
+    assert_eq!(range.len_days(), solar_date_range.len_days());
+}
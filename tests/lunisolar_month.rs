@@ -0,0 +1,100 @@
+use chinese_lunisolar_calendar::{
+    LunarMonth, LunisolarDate, LunisolarDateError, LunisolarMonth, LunisolarYear, SolarDate,
+};
+
+#[test]
+fn from_ym() {
+    let lunisolar_month = LunisolarMonth::from_ym(1993, 3, true).unwrap();
+
+    assert_eq!(
+        LunisolarYear::from_solar_year(1993.into()).unwrap(),
+        lunisolar_month.to_lunisolar_year()
+    );
+    assert_eq!(LunarMonth::LeapThird, lunisolar_month.to_lunar_month());
+
+    assert_eq!(Err(LunisolarDateError::MonthIncorrect), LunisolarMonth::from_ym(1993, 4, true));
+    assert_eq!(Err(LunisolarDateError::MonthIncorrect), LunisolarMonth::from_ym(1993, 13, false));
+    assert_eq!(Err(LunisolarDateError::OutOfRange), LunisolarMonth::from_ym(2101, 1, false));
+}
+
+#[test]
+fn first_last_day() {
+    let lunisolar_month = LunisolarMonth::from_ym(1992, 12, false).unwrap();
+
+    assert_eq!(30, lunisolar_month.total_days());
+    assert_eq!(LunisolarDate::from_ymd(1992, 12, false, 1).unwrap(), lunisolar_month.first_day());
+    assert_eq!(LunisolarDate::from_ymd(1992, 12, false, 30).unwrap(), lunisolar_month.last_day());
+    assert_eq!(
+        SolarDate::from_ymd(1992, 12, 24).unwrap(),
+        lunisolar_month.first_day_in_solar_date()
+    );
+    assert_eq!(SolarDate::from_ymd(1993, 1, 22).unwrap(), lunisolar_month.last_day_in_solar_date());
+
+    let lunisolar_month = LunisolarMonth::from_ym(1993, 3, true).unwrap();
+
+    assert_eq!(29, lunisolar_month.total_days());
+    assert_eq!(
+        SolarDate::from_ymd(1993, 4, 22).unwrap(),
+        lunisolar_month.first_day_in_solar_date()
+    );
+}
+
+#[test]
+fn next_prev() {
+    let mut lunisolar_month = LunisolarMonth::from_ym(1901, 1, false).unwrap();
+
+    assert!(lunisolar_month.prev().is_err());
+
+    let mut count = 1;
+
+    while let Ok(next) = lunisolar_month.next() {
+        assert!(next > lunisolar_month);
+        assert_eq!(lunisolar_month, next.prev().unwrap());
+        assert_eq!(lunisolar_month.last_day().succ().unwrap(), next.first_day());
+
+        lunisolar_month = next;
+        count += 1;
+    }
+
+    assert_eq!(LunisolarMonth::from_ym(2100, 12, false).unwrap(), lunisolar_month);
+    assert_eq!(2473, count);
+}
+
+#[test]
+fn parse_str() {
+    let lunisolar_month = LunisolarMonth::parse_str("一九九三　癸酉、雞年　閏三月").unwrap();
+
+    assert_eq!(LunisolarMonth::from_ym(1993, 3, true).unwrap(), lunisolar_month);
+
+    let lunisolar_month = LunisolarMonth::parse_str("一九九三年闰三月").unwrap();
+
+    assert_eq!(LunisolarMonth::from_ym(1993, 3, true).unwrap(), lunisolar_month);
+
+    assert!(LunisolarMonth::parse_str("一九九三年閏四月").is_err());
+}
+
+#[test]
+fn to_string() {
+    let lunisolar_month = LunisolarMonth::from_ym(1993, 3, true).unwrap();
+
+    assert_eq!("一九九三　癸酉、雞年　閏三月", lunisolar_month.to_string());
+    assert_eq!("一九九三　癸酉、鸡年　闰三月", format!("{lunisolar_month:#}"));
+    assert_eq!(lunisolar_month, LunisolarMonth::parse_str(lunisolar_month.to_string()).unwrap());
+    assert_eq!(lunisolar_month, LunisolarMonth::parse_str(format!("{lunisolar_month:#}")).unwrap());
+}
+
+#[test]
+fn days() {
+    let lunisolar_month = LunisolarMonth::from_ym(1993, 3, true).unwrap();
+
+    let days: Vec<LunisolarDate> = lunisolar_month.days().collect();
+
+    assert_eq!(29, days.len());
+    assert_eq!(lunisolar_month.first_day(), days[0]);
+    assert_eq!(lunisolar_month.last_day(), days[28]);
+    assert!(days.iter().all(|d| d.to_lunisolar_month() == lunisolar_month));
+
+    let reversed: Vec<LunisolarDate> = lunisolar_month.days().rev().collect();
+
+    assert_eq!(days.into_iter().rev().collect::<Vec<_>>(), reversed);
+}
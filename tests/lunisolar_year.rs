@@ -1,5 +1,6 @@
 use chinese_lunisolar_calendar::{
-    EarthlyBranch, HeavenlyStems, LunarMonth, LunarYear, LunisolarYear, Zodiac,
+    EarthlyBranch, HeavenlyStems, LunarMonth, LunarYear, LunisolarDate, LunisolarYear,
+    MAX_LUNISOLAR_DATE_IN_SOLAR_DATE, SolarDate, Zodiac,
 };
 
 #[test]
@@ -92,3 +93,120 @@ fn to_lunar_year() {
         LunisolarYear::from_solar_year(2018.into()).unwrap().to_lunar_year()
     );
 }
+
+#[test]
+fn months() {
+    let lunisolar_year = LunisolarYear::from_solar_year(1993.into()).unwrap();
+
+    let months: Vec<LunarMonth> = lunisolar_year.months().map(|m| m.to_lunar_month()).collect();
+
+    assert_eq!(
+        vec![
+            LunarMonth::First,
+            LunarMonth::Second,
+            LunarMonth::Third,
+            LunarMonth::LeapThird,
+            LunarMonth::Fourth,
+            LunarMonth::Fifth,
+            LunarMonth::Sixth,
+            LunarMonth::Seventh,
+            LunarMonth::Eighth,
+            LunarMonth::Ninth,
+            LunarMonth::Tenth,
+            LunarMonth::Eleventh,
+            LunarMonth::Twelfth,
+        ],
+        months
+    );
+
+    assert_eq!(
+        lunisolar_year.get_total_days(),
+        lunisolar_year.months().map(|m| m.total_days() as u16).sum::<u16>()
+    );
+
+    let lunisolar_year = LunisolarYear::from_solar_year(2018.into()).unwrap();
+
+    assert_eq!(12, lunisolar_year.months().len());
+    assert_eq!(
+        Some(LunarMonth::Twelfth),
+        lunisolar_year.months().next_back().map(|m| m.to_lunar_month())
+    );
+}
+
+#[test]
+fn days() {
+    for year in 1901..=2100 {
+        let lunisolar_year = LunisolarYear::from_solar_year(year.into()).unwrap();
+
+        let mut days = lunisolar_year.days();
+
+        assert_eq!(lunisolar_year.get_total_days() as usize, days.len());
+
+        let mut previous = days.next().unwrap();
+
+        assert_eq!(1, previous.the_n_day_in_this_year());
+
+        for lunisolar_date in days {
+            assert_eq!(previous.succ().unwrap(), lunisolar_date);
+
+            previous = lunisolar_date;
+        }
+
+        assert_eq!(Some(previous), lunisolar_year.days().next_back());
+    }
+}
+
+#[test]
+fn new_year_solar_date() {
+    assert_eq!(
+        SolarDate::from_ymd(1901, 2, 19).unwrap(),
+        LunisolarYear::from_solar_year(1901.into()).unwrap().new_year_solar_date()
+    );
+    assert_eq!(
+        SolarDate::from_ymd(1993, 1, 23).unwrap(),
+        LunisolarYear::from_solar_year(1993.into()).unwrap().new_year_solar_date()
+    );
+    assert_eq!(
+        SolarDate::from_ymd(2100, 2, 9).unwrap(),
+        LunisolarYear::from_solar_year(2100.into()).unwrap().new_year_solar_date()
+    );
+
+    for year in 1901..=2100 {
+        let lunisolar_year = LunisolarYear::from_solar_year(year.into()).unwrap();
+
+        assert_eq!(
+            lunisolar_year.range().start().to_solar_date(),
+            lunisolar_year.new_year_solar_date()
+        );
+    }
+}
+
+#[test]
+fn new_years_eve() {
+    assert_eq!(
+        LunisolarDate::from_ymd(1992, 12, false, 30).unwrap(),
+        LunisolarYear::from_solar_year(1992.into()).unwrap().new_years_eve()
+    );
+    assert_eq!(
+        LunisolarDate::from_ymd(1993, 12, false, 29).unwrap(),
+        LunisolarYear::from_solar_year(1993.into()).unwrap().new_years_eve()
+    );
+
+    for year in 1901..2100 {
+        let new_years_eve = LunisolarYear::from_solar_year(year.into()).unwrap().new_years_eve();
+
+        assert_eq!(LunarMonth::Twelfth, new_years_eve.to_lunar_month());
+        assert_eq!(
+            LunisolarYear::from_solar_year((year + 1).into()).unwrap().new_year_solar_date(),
+            new_years_eve.succ().unwrap().to_solar_date()
+        );
+    }
+}
+
+#[test]
+fn solar_date_range() {
+    let solar_date_range = LunisolarYear::from_solar_year(2100.into()).unwrap().solar_date_range();
+
+    assert_eq!(SolarDate::from_ymd(2100, 2, 9).unwrap(), solar_date_range.start());
+    assert_eq!(MAX_LUNISOLAR_DATE_IN_SOLAR_DATE, solar_date_range.end());
+}
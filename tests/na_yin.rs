@@ -0,0 +1,55 @@
+use chinese_lunisolar_calendar::{ChineseVariant, FiveElements, LunarYear, NaYin, Sexagenary};
+
+#[test]
+fn from_sexagenary() {
+    let na_yin = |s| Sexagenary::parse_str(s).unwrap().nayin().to_str(ChineseVariant::Traditional);
+
+    assert_eq!("海中金", na_yin("甲子"));
+    assert_eq!("海中金", na_yin("乙丑"));
+    assert_eq!("爐中火", na_yin("丙寅"));
+    assert_eq!("路旁土", na_yin("庚午"));
+    assert_eq!("霹靂火", na_yin("己丑"));
+    assert_eq!("覆燈火", na_yin("甲辰"));
+    assert_eq!("天上火", na_yin("己未"));
+    assert_eq!("大海水", na_yin("癸亥"));
+
+    for sexagenary in Sexagenary::iter() {
+        let na_yin = sexagenary.nayin();
+
+        assert!(na_yin.to_sexagenaries().contains(&sexagenary));
+        assert_eq!(na_yin, NaYin::from(sexagenary));
+    }
+}
+
+#[test]
+fn to_str() {
+    let na_yin = LunarYear::parse_str("戊子").unwrap().nayin();
+
+    assert_eq!("霹靂火", na_yin.to_str(ChineseVariant::Traditional));
+    assert_eq!("霹雳火", na_yin.to_str(ChineseVariant::Simple));
+    assert_eq!("霹靂火", na_yin.to_string());
+    assert_eq!("霹雳火", format!("{na_yin:#}"));
+}
+
+#[test]
+fn to_five_elements() {
+    for sexagenary in Sexagenary::iter() {
+        let na_yin = sexagenary.nayin();
+
+        let last_char = na_yin.to_str(ChineseVariant::Traditional).chars().last().unwrap();
+
+        assert_eq!(FiveElements::from_char(last_char).unwrap(), na_yin.to_five_elements());
+    }
+}
+
+#[test]
+fn parse_str() {
+    for sexagenary in Sexagenary::iter() {
+        let na_yin = sexagenary.nayin();
+
+        assert_eq!(na_yin, NaYin::parse_str(na_yin.to_str(ChineseVariant::Traditional)).unwrap());
+        assert_eq!(na_yin, NaYin::parse_str(na_yin.to_str(ChineseVariant::Simple)).unwrap());
+    }
+
+    assert!(NaYin::parse_str("海中火").is_none());
+}
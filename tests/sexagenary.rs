@@ -0,0 +1,187 @@
+use chinese_lunisolar_calendar::{
+    EarthlyBranch, HeavenlyStems, LunarYear, Sexagenary, chrono::NaiveTime,
+};
+
+#[test]
+fn from_ordinal() {
+    assert_eq!("甲子", Sexagenary::from_ordinal(1).unwrap().to_str());
+    assert_eq!("甲戌", Sexagenary::from_ordinal(11).unwrap().to_str());
+    assert_eq!("癸亥", Sexagenary::from_ordinal(60).unwrap().to_str());
+    assert!(Sexagenary::from_ordinal(0).is_err());
+    assert!(Sexagenary::from_ordinal(61).is_err());
+}
+
+#[test]
+fn from_stem_branch() {
+    for sexagenary in Sexagenary::iter() {
+        assert_eq!(
+            sexagenary,
+            Sexagenary::from_stem_branch(
+                sexagenary.to_heavenly_stems(),
+                sexagenary.to_earthly_branch()
+            )
+            .unwrap()
+        );
+    }
+
+    assert_eq!(
+        "甲辰",
+        Sexagenary::from_stem_branch(HeavenlyStems::First, EarthlyBranch::Fifth).unwrap().to_str()
+    );
+    assert!(Sexagenary::from_stem_branch(HeavenlyStems::First, EarthlyBranch::Twelfth).is_err());
+}
+
+#[test]
+fn parse_str() {
+    let sexagenary = Sexagenary::parse_str("甲午").unwrap();
+
+    assert_eq!(HeavenlyStems::First, sexagenary.to_heavenly_stems());
+    assert_eq!(EarthlyBranch::Seventh, sexagenary.to_earthly_branch());
+    assert_eq!(sexagenary, "甲午".parse().unwrap());
+    assert_eq!("甲午", sexagenary.to_string());
+    assert!(Sexagenary::parse_str("甲丑").is_err());
+}
+
+#[test]
+fn add_sub() {
+    let jia_zi = Sexagenary::from_ordinal(1).unwrap();
+    let gui_hai = Sexagenary::from_ordinal(60).unwrap();
+
+    assert_eq!(gui_hai, jia_zi - 1);
+    assert_eq!(jia_zi, gui_hai + 1);
+    assert_eq!(jia_zi, jia_zi + 60);
+    assert_eq!(jia_zi, jia_zi - 600);
+    assert_eq!("甲辰", (jia_zi + 40).to_str());
+    assert_eq!("甲辰", (jia_zi - 20).to_str());
+    assert_eq!("甲辰", (jia_zi + (i32::MAX - 27)).to_str());
+    assert_eq!("甲辰", (jia_zi - (i32::MIN + 28)).to_str());
+
+    let mut sexagenary = jia_zi;
+
+    sexagenary += 61;
+    assert_eq!("乙丑", sexagenary.to_str());
+
+    sexagenary -= 2;
+    assert_eq!(gui_hai, sexagenary);
+
+    assert_eq!(59, gui_hai - jia_zi);
+    assert_eq!(1, jia_zi - gui_hai);
+    assert_eq!(0, jia_zi - jia_zi);
+}
+
+#[test]
+fn iter() {
+    let mut iter = Sexagenary::iter();
+
+    for ordinal in 1..=60 {
+        assert_eq!(ordinal, iter.next().unwrap().ordinal());
+    }
+
+    assert!(iter.next().is_none());
+
+    let mut iter = Sexagenary::iter();
+
+    assert_eq!(60, iter.len());
+    assert_eq!("癸亥", iter.next_back().unwrap().to_str());
+    assert_eq!(59, iter.len());
+}
+
+#[test]
+fn lunar_year() {
+    for sexagenary in Sexagenary::iter() {
+        let lunar_year = LunarYear::from(sexagenary);
+
+        assert_eq!(sexagenary.to_str(), lunar_year.to_str());
+        assert_eq!(sexagenary.to_heavenly_stems(), lunar_year.to_heavenly_stems());
+        assert_eq!(sexagenary.to_earthly_branch(), lunar_year.to_earthly_branch());
+        assert_eq!(sexagenary, Sexagenary::from(lunar_year));
+    }
+}
+
+#[test]
+fn from_day_stem_and_hour_branch() {
+    let first_hours =
+        ["甲子", "丙子", "戊子", "庚子", "壬子", "甲子", "丙子", "戊子", "庚子", "壬子"];
+
+    for (c, first_hour) in "甲乙丙丁戊己庚辛壬癸".chars().zip(first_hours) {
+        let day_stem = HeavenlyStems::from_char(c).unwrap();
+
+        let first_hour = Sexagenary::parse_str(first_hour).unwrap();
+
+        for (i, c) in "子丑寅卯辰巳午未申酉戌亥".chars().enumerate() {
+            let hour_branch = EarthlyBranch::from_char(c).unwrap();
+
+            assert_eq!(
+                first_hour + i as i32,
+                Sexagenary::from_day_stem_and_hour_branch(day_stem, hour_branch)
+            );
+        }
+    }
+}
+
+#[test]
+fn from_day_stem_and_time() {
+    let time = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+
+    let from = |day_stem, time| Sexagenary::from_day_stem_and_time(day_stem, time).to_string();
+
+    assert_eq!("甲子", from(HeavenlyStems::First, time(0, 0)));
+    assert_eq!("乙丑", from(HeavenlyStems::First, time(1, 0)));
+    assert_eq!("丙寅", from(HeavenlyStems::First, time(4, 59)));
+    assert_eq!("乙亥", from(HeavenlyStems::First, time(22, 59)));
+    assert_eq!("丙子", from(HeavenlyStems::First, time(23, 0)));
+    assert_eq!("甲子", from(HeavenlyStems::Tenth, time(23, 59)));
+    assert_eq!("壬子", from(HeavenlyStems::Tenth, time(0, 30)));
+    // 丁日的晚子時以戊日推算
+    assert_eq!("庚子", from(HeavenlyStems::Fourth, time(0, 30)));
+    assert_eq!("壬子", from(HeavenlyStems::Fourth, time(23, 30)));
+}
+
+#[test]
+fn xun_and_void_branches() {
+    let expected = [
+        ("甲子", "戌亥"),
+        ("甲戌", "申酉"),
+        ("甲申", "午未"),
+        ("甲午", "辰巳"),
+        ("甲辰", "寅卯"),
+        ("甲寅", "子丑"),
+    ];
+
+    for sexagenary in Sexagenary::iter() {
+        let (xun, void_branches) = expected[(sexagenary.ordinal() as usize - 1) / 10];
+
+        assert_eq!(xun, sexagenary.xun().to_str());
+        assert!(
+            sexagenary
+                .void_branches()
+                .iter()
+                .map(|earthly_branch| earthly_branch.to_char())
+                .eq(void_branches.chars())
+        );
+
+        // 空亡的地支不會出現在同一旬中
+        for i in 0..10 {
+            let earthly_branch = (sexagenary.xun() + i).to_earthly_branch();
+
+            assert!(!sexagenary.void_branches().contains(&earthly_branch));
+        }
+
+        let lunar_year = LunarYear::from_sexagenary(sexagenary);
+
+        assert_eq!(sexagenary.xun(), lunar_year.xun());
+        assert_eq!(sexagenary.void_branches(), lunar_year.void_branches());
+
+        let (heavenly_stems, earthly_branch) =
+            (sexagenary.to_heavenly_stems(), sexagenary.to_earthly_branch());
+
+        assert_eq!(Ok(sexagenary.xun()), Sexagenary::xun_of(heavenly_stems, earthly_branch));
+        assert_eq!(
+            Ok(sexagenary.void_branches()),
+            Sexagenary::void_branches_of(heavenly_stems, earthly_branch)
+        );
+    }
+
+    assert!(Sexagenary::xun_of(HeavenlyStems::First, EarthlyBranch::Second).is_err());
+    assert!(Sexagenary::void_branches_of(HeavenlyStems::First, EarthlyBranch::Second).is_err());
+}
@@ -1,5 +1,6 @@
 use chinese_lunisolar_calendar::{
-    LunisolarDate, SolarDate, SolarDay, SolarMonth, SolarYear, chrono::prelude::*,
+    LunisolarDate, MAX_LUNISOLAR_DATE_IN_SOLAR_DATE, MIN_LUNISOLAR_DATE_IN_SOLAR_DATE, SolarDate,
+    SolarDay, SolarMonth, SolarYear, chrono::prelude::*,
 };
 
 #[test]
@@ -90,3 +91,169 @@ fn the_n_day_in_this_year() {
     assert_eq!(95, SolarDate::from_ymd(2020, 4, 4).unwrap().the_n_day_in_this_year());
     assert_eq!(125, SolarDate::from_ymd(2020, 5, 4).unwrap().the_n_day_in_this_year());
 }
+
+#[test]
+fn julian_day_number() {
+    let solar_date = SolarDate::from_ymd(2000, 1, 1).unwrap();
+
+    assert_eq!(2451545, solar_date.to_julian_day_number());
+    assert_eq!(730120, solar_date.to_rata_die());
+
+    assert_eq!(SolarDate::from_ymd(0, 1, 1).unwrap(), SolarDate::from_rata_die(-365).unwrap());
+    assert_eq!(
+        SolarDate::from_ymd(1582, 10, 15).unwrap(),
+        SolarDate::from_julian_day_number(2299161).unwrap()
+    );
+    assert!(SolarDate::from_rata_die(-366).is_err());
+    assert!(SolarDate::from_julian_day_number(i32::MIN).is_err());
+
+    let mut naive_date = NaiveDate::from_ymd_opt(1800, 1, 1).unwrap();
+
+    while naive_date.year() < 2200 {
+        let solar_date = SolarDate::from_date(naive_date).unwrap();
+
+        assert_eq!(naive_date.num_days_from_ce(), solar_date.to_rata_die());
+        assert_eq!(solar_date, SolarDate::from_rata_die(solar_date.to_rata_die()).unwrap());
+        assert_eq!(
+            solar_date,
+            SolarDate::from_julian_day_number(solar_date.to_julian_day_number()).unwrap()
+        );
+
+        naive_date = naive_date.succ_opt().unwrap();
+    }
+
+    let max = SolarDate::from_ymd(u16::MAX, 12, 31).unwrap();
+
+    assert_eq!(max, SolarDate::from_rata_die(max.to_rata_die()).unwrap());
+    assert!(SolarDate::from_rata_die(max.to_rata_die() + 1).is_err());
+}
+
+#[test]
+fn weekday() {
+    assert_eq!(Weekday::Mon, SolarDate::from_ymd(1, 1, 1).unwrap().weekday());
+    assert_eq!(Weekday::Sat, SolarDate::from_ymd(2000, 1, 1).unwrap().weekday());
+
+    let mut naive_date = NaiveDate::from_ymd_opt(0, 1, 1).unwrap();
+
+    while naive_date.year() < 2200 {
+        assert_eq!(naive_date.weekday(), SolarDate::from_date(naive_date).unwrap().weekday());
+
+        naive_date = naive_date.succ_opt().unwrap();
+    }
+}
+
+#[test]
+fn day_stem_branch() {
+    assert_eq!("甲子", SolarDate::from_ymd(1949, 10, 1).unwrap().day_stem_branch().to_str());
+    assert_eq!("戊午", SolarDate::from_ymd(2000, 1, 1).unwrap().day_stem_branch().to_str());
+    assert_eq!("庚辰", SolarDate::from_ymd(2008, 8, 8).unwrap().day_stem_branch().to_str());
+    assert_eq!("甲辰", SolarDate::from_ymd(2024, 2, 10).unwrap().day_stem_branch().to_str());
+    assert_eq!("己卯", SolarDate::from_ymd(1, 1, 1).unwrap().day_stem_branch().to_str());
+
+    let mut naive_date = NaiveDate::from_ymd_opt(1800, 1, 1).unwrap();
+    let mut day_stem_branch = SolarDate::from_date(naive_date).unwrap().day_stem_branch();
+
+    while naive_date.year() < 2200 {
+        naive_date = naive_date.succ_opt().unwrap();
+        day_stem_branch += 1;
+
+        assert_eq!(day_stem_branch, SolarDate::from_date(naive_date).unwrap().day_stem_branch());
+    }
+}
+
+#[test]
+fn year_stem_branch() {
+    let year_stem_branch = |y, m, d, h, min| {
+        SolarDate::from_ymd(y, m, d)
+            .unwrap()
+            .year_stem_branch(NaiveTime::from_hms_opt(h, min, 0).unwrap())
+            .unwrap()
+            .to_string()
+    };
+
+    // 2024-02-04 16:27 立春
+    assert_eq!("癸卯", year_stem_branch(2024, 2, 3, 23, 59));
+    assert_eq!("癸卯", year_stem_branch(2024, 2, 4, 16, 26));
+    assert_eq!("甲辰", year_stem_branch(2024, 2, 4, 16, 27));
+    assert_eq!("甲辰", year_stem_branch(2024, 2, 10, 0, 0));
+    assert_eq!("己丑", year_stem_branch(1949, 10, 1, 12, 0));
+
+    let midnight = NaiveTime::from_hms_opt(0, 0, 0).unwrap();
+
+    assert!(SolarDate::from_ymd(1901, 2, 18).unwrap().year_stem_branch(midnight).is_err());
+    assert!(SolarDate::from_ymd(2101, 1, 29).unwrap().year_stem_branch(midnight).is_err());
+}
+
+#[test]
+fn month_stem_branch() {
+    let month_stem_branch = |y, m, d, h, min| {
+        SolarDate::from_ymd(y, m, d)
+            .unwrap()
+            .month_stem_branch(NaiveTime::from_hms_opt(h, min, 0).unwrap())
+            .unwrap()
+            .to_string()
+    };
+
+    assert_eq!("癸酉", month_stem_branch(1949, 10, 1, 12, 0));
+    assert_eq!("丙子", month_stem_branch(2000, 1, 1, 12, 0));
+    assert_eq!("庚申", month_stem_branch(2008, 8, 8, 12, 0));
+    assert_eq!("丙寅", month_stem_branch(2024, 2, 10, 12, 0));
+    assert_eq!("乙丑", month_stem_branch(2024, 2, 3, 12, 0));
+    assert_eq!("甲子", month_stem_branch(2024, 1, 5, 12, 0));
+    assert_eq!("乙丑", month_stem_branch(2024, 1, 6, 12, 0));
+    assert_eq!("甲子", month_stem_branch(2023, 12, 31, 12, 0));
+    assert_eq!("癸亥", month_stem_branch(2023, 12, 6, 12, 0));
+
+    // 交節時刻精確到分鐘
+    assert_eq!("乙丑", month_stem_branch(2024, 2, 4, 16, 26));
+    assert_eq!("丙寅", month_stem_branch(2024, 2, 4, 16, 27));
+    assert_eq!("甲子", month_stem_branch(2024, 1, 6, 4, 48));
+    assert_eq!("乙丑", month_stem_branch(2024, 1, 6, 4, 49));
+    assert_eq!("癸亥", month_stem_branch(2023, 12, 7, 17, 32));
+    assert_eq!("甲子", month_stem_branch(2023, 12, 7, 17, 33));
+    assert_eq!("癸酉", month_stem_branch(1949, 10, 8, 23, 10));
+    assert_eq!("甲戌", month_stem_branch(1949, 10, 8, 23, 11));
+    assert_eq!("戊子", month_stem_branch(2101, 1, 5, 21, 6));
+    assert_eq!("己丑", month_stem_branch(2101, 1, 5, 21, 7));
+
+    let midnight = NaiveTime::from_hms_opt(0, 0, 0).unwrap();
+
+    assert!(SolarDate::from_ymd(1901, 2, 18).unwrap().month_stem_branch(midnight).is_err());
+    assert!(SolarDate::from_ymd(2101, 1, 29).unwrap().month_stem_branch(midnight).is_err());
+
+    let noon = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+
+    let mut naive_date = MIN_LUNISOLAR_DATE_IN_SOLAR_DATE.to_naive_date();
+    let mut month_stem_branch = MIN_LUNISOLAR_DATE_IN_SOLAR_DATE.month_stem_branch(noon).unwrap();
+    let mut days = 0;
+    let mut first = true;
+
+    while naive_date < MAX_LUNISOLAR_DATE_IN_SOLAR_DATE.to_naive_date() {
+        naive_date = naive_date.succ_opt().unwrap();
+        days += 1;
+
+        let next = SolarDate::from_date(naive_date).unwrap().month_stem_branch(noon).unwrap();
+
+        if next != month_stem_branch {
+            assert_eq!(month_stem_branch + 1, next);
+            assert!(first || (29..=32).contains(&days), "{naive_date}");
+
+            month_stem_branch = next;
+            days = 0;
+            first = false;
+        }
+    }
+}
+
+#[test]
+fn hour_stem_branch() {
+    let solar_date = SolarDate::from_ymd(2008, 8, 8).unwrap();
+
+    let hour_stem_branch =
+        |h| solar_date.hour_stem_branch(NaiveTime::from_hms_opt(h, 0, 0).unwrap()).to_string();
+
+    assert_eq!("丙子", hour_stem_branch(0));
+    assert_eq!("壬午", hour_stem_branch(12));
+    assert_eq!("丙戌", hour_stem_branch(20));
+    assert_eq!("戊子", hour_stem_branch(23));
+}
@@ -0,0 +1,74 @@
+use chinese_lunisolar_calendar::{SolarDate, SolarDateRange};
+
+#[test]
+fn new() {
+    let solar_date_1 = SolarDate::from_ymd(2024, 1, 1).unwrap();
+    let solar_date_2 = SolarDate::from_ymd(2024, 12, 31).unwrap();
+
+    assert!(SolarDateRange::new(solar_date_1, solar_date_2).is_some());
+    assert!(SolarDateRange::new(solar_date_2, solar_date_1).is_none());
+}
+
+#[test]
+fn len_days() {
+    let range = SolarDateRange::new(
+        SolarDate::from_ymd(2024, 1, 1).unwrap(),
+        SolarDate::from_ymd(2024, 12, 31).unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(366, range.len_days());
+}
+
+#[test]
+fn contains() {
+    let range = SolarDateRange::new(
+        SolarDate::from_ymd(2024, 2, 10).unwrap(),
+        SolarDate::from_ymd(2024, 2, 24).unwrap(),
+    )
+    .unwrap();
+
+    assert!(range.contains(SolarDate::from_ymd(2024, 2, 10).unwrap()));
+    assert!(range.contains(SolarDate::from_ymd(2024, 2, 24).unwrap()));
+    assert!(!range.contains(SolarDate::from_ymd(2024, 2, 9).unwrap()));
+    assert!(!range.contains(SolarDate::from_ymd(2024, 2, 25).unwrap()));
+}
+
+#[test]
+fn intersection() {
+    let range_1 = SolarDateRange::new(
+        SolarDate::from_ymd(2024, 2, 10).unwrap(),
+        SolarDate::from_ymd(2024, 2, 24).unwrap(),
+    )
+    .unwrap();
+    let range_2 = SolarDateRange::new(
+        SolarDate::from_ymd(2024, 2, 25).unwrap(),
+        SolarDate::from_ymd(2024, 3, 1).unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(None, range_1.intersection(range_2));
+    assert_eq!(Some(range_1), range_1.intersection(range_1));
+}
+
+#[test]
+fn iter() {
+    let range = SolarDateRange::new(
+        SolarDate::from_ymd(2023, 12, 30).unwrap(),
+        SolarDate::from_ymd(2024, 1, 2).unwrap(),
+    )
+    .unwrap();
+
+    let dates: Vec<SolarDate> = range.into_iter().collect();
+
+    assert_eq!(
+        vec![
+            SolarDate::from_ymd(2023, 12, 30).unwrap(),
+            SolarDate::from_ymd(2023, 12, 31).unwrap(),
+            SolarDate::from_ymd(2024, 1, 1).unwrap(),
+            SolarDate::from_ymd(2024, 1, 2).unwrap(),
+        ],
+        dates
+    );
+    assert_eq!(dates.into_iter().rev().collect::<Vec<_>>(), range.iter().rev().collect::<Vec<_>>());
+}
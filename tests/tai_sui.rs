@@ -0,0 +1,111 @@
+use chinese_lunisolar_calendar::{
+    ChineseVariant, EarthlyBranch, LunisolarYear, SolarYear, TaiSuiConflict, TaiSuiConflicts,
+    Zodiac,
+};
+
+#[test]
+fn tai_sui_conflicts() {
+    let lunisolar_year = LunisolarYear::from_solar_year(SolarYear::from_u16(2025)).unwrap();
+
+    let conflicts = |c| Zodiac::from_char(c).unwrap().tai_sui_conflicts(lunisolar_year);
+
+    assert_eq!(vec![TaiSuiConflict::Birth], conflicts('蛇').iter().collect::<Vec<_>>());
+    assert_eq!(vec![TaiSuiConflict::Clash], conflicts('豬').iter().collect::<Vec<_>>());
+    assert_eq!(
+        vec![TaiSuiConflict::Punishment, TaiSuiConflict::Harm],
+        conflicts('虎').iter().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![TaiSuiConflict::Punishment, TaiSuiConflict::Break],
+        conflicts('猴').iter().collect::<Vec<_>>()
+    );
+    assert!(conflicts('鼠').is_empty());
+    assert_eq!(2, conflicts('猴').len());
+
+    assert_eq!("刑太歲、害太歲", conflicts('虎').to_string());
+    assert_eq!("刑太岁、破太岁", format!("{:#}", conflicts('猴')));
+    assert_eq!("", conflicts('鼠').to_string());
+
+    assert_eq!(
+        TaiSuiConflicts::new(EarthlyBranch::Eleventh, EarthlyBranch::Fifth),
+        EarthlyBranch::Eleventh
+            .tai_sui_conflicts(LunisolarYear::from_solar_year(SolarYear::from_u16(2024)).unwrap())
+    );
+}
+
+#[test]
+fn conflicting_zodiacs() {
+    let zodiacs = |year| {
+        LunisolarYear::from_solar_year(SolarYear::from_u16(year))
+            .unwrap()
+            .conflicting_zodiacs()
+            .map(|(zodiac, _)| zodiac.to_char(ChineseVariant::Traditional))
+            .collect::<String>()
+    };
+
+    assert_eq!("牛兔龍狗", zodiacs(2024));
+    assert_eq!("虎蛇猴豬", zodiacs(2025));
+    assert_eq!("鼠牛兔馬", zodiacs(2026));
+    assert_eq!("鼠兔龍馬雞", zodiacs(2023));
+    assert_eq!("鼠牛羊狗", zodiacs(2027));
+
+    for year in 1901..=2100 {
+        let lunisolar_year = LunisolarYear::from_solar_year(SolarYear::from_u16(year)).unwrap();
+
+        for (zodiac, conflicts) in lunisolar_year.conflicting_zodiacs() {
+            assert!(!conflicts.is_empty());
+            assert_eq!(conflicts, zodiac.tai_sui_conflicts(lunisolar_year));
+        }
+
+        assert!(
+            lunisolar_year
+                .conflicting_zodiacs()
+                .any(|(zodiac, _)| zodiac == lunisolar_year.to_zodiac())
+        );
+
+        let mut iter = lunisolar_year.conflicting_zodiacs();
+
+        while let (lower, Some(upper)) = iter.size_hint() {
+            let remaining = iter.clone().count();
+
+            assert!(lower <= remaining && remaining <= upper);
+
+            if iter.next().is_none() {
+                break;
+            }
+        }
+    }
+}
+
+#[test]
+fn to_str() {
+    assert_eq!("本命年", TaiSuiConflict::Birth.to_str(ChineseVariant::Simple));
+    assert_eq!("沖太歲", TaiSuiConflict::Clash.to_str(ChineseVariant::Traditional));
+    assert_eq!("害太岁", TaiSuiConflict::Harm.to_str(ChineseVariant::Simple));
+    assert_eq!("破太歲", TaiSuiConflict::Break.to_string());
+}
+
+#[test]
+fn tai_sui_name() {
+    let name = |year: u16, chinese_variant| {
+        LunisolarYear::from_solar_year(SolarYear::from_u16(year))
+            .unwrap()
+            .to_lunar_year()
+            .tai_sui_name(chinese_variant)
+    };
+
+    assert_eq!("金辨大將軍", name(1924, ChineseVariant::Traditional));
+    assert_eq!("陳材大將軍", name(1925, ChineseVariant::Traditional));
+    assert_eq!("耿章大將軍", name(1926, ChineseVariant::Traditional));
+    assert_eq!("沈興大將軍", name(1927, ChineseVariant::Traditional));
+    assert_eq!("施廣大將軍", name(1934, ChineseVariant::Traditional));
+    assert_eq!("陸明大將軍", name(1942, ChineseVariant::Traditional));
+    assert_eq!("虞起大將軍", name(2020, ChineseVariant::Traditional));
+    assert_eq!("皮時大將軍", name(2023, ChineseVariant::Traditional));
+    assert_eq!("李誠大將軍", name(2024, ChineseVariant::Traditional));
+    assert_eq!("吳遂大將軍", name(2025, ChineseVariant::Traditional));
+    assert_eq!("吴遂大将军", name(2025, ChineseVariant::Simple));
+    assert_eq!("文折大將軍", name(2026, ChineseVariant::Traditional));
+    assert_eq!("虞程大將軍", name(1983, ChineseVariant::Traditional));
+    assert_eq!("虞程大将军", name(1983, ChineseVariant::Simple));
+}
@@ -0,0 +1,78 @@
+use chinese_lunisolar_calendar::{
+    ChineseVariant, EarthlyBranch, FourPillars, HeavenlyStems, TenGods,
+};
+
+#[test]
+fn from_stems() {
+    let ten_gods = |d, s| {
+        TenGods::from_stems(
+            HeavenlyStems::from_char(d).unwrap(),
+            HeavenlyStems::from_char(s).unwrap(),
+        )
+        .to_str(ChineseVariant::Traditional)
+    };
+
+    let expected = ["比肩", "劫財", "食神", "傷官", "偏財", "正財", "七殺", "正官", "偏印", "正印"];
+
+    for (s, expected) in "甲乙丙丁戊己庚辛壬癸".chars().zip(expected) {
+        assert_eq!(expected, ten_gods('甲', s));
+    }
+
+    let expected = ["正財", "偏財", "正官", "七殺", "正印", "偏印", "劫財", "比肩", "傷官", "食神"];
+
+    for (s, expected) in "甲乙丙丁戊己庚辛壬癸".chars().zip(expected) {
+        assert_eq!(expected, ten_gods('辛', s));
+    }
+
+    assert_eq!("正官", ten_gods('癸', '戊'));
+    assert_eq!("傷官", ten_gods('己', '庚'));
+
+    for d in "甲乙丙丁戊己庚辛壬癸".chars() {
+        assert_eq!("比肩", ten_gods(d, d));
+    }
+}
+
+#[test]
+fn to_ten_gods() {
+    assert_eq!(TenGods::IndirectResource, HeavenlyStems::Third.to_ten_gods(HeavenlyStems::Fifth));
+    assert_eq!(TenGods::IndirectWealth, EarthlyBranch::Twelfth.to_ten_gods(HeavenlyStems::Fifth));
+    assert_eq!(
+        [Some(TenGods::SevenKillings), Some(TenGods::IndirectResource), None],
+        EarthlyBranch::Seventh.hidden_stems().to_ten_gods(HeavenlyStems::Eighth)
+    );
+
+    let four_pillars = FourPillars::parse_str("戊子 庚申 庚辰 丙戌").unwrap();
+
+    let [year, month, day, hour] = four_pillars.to_ten_gods();
+
+    assert_eq!(Some(TenGods::IndirectResource), year.heavenly_stems);
+    assert_eq!([Some(TenGods::HurtingOfficer), None, None], year.hidden_stems);
+    assert_eq!(Some(TenGods::Companion), month.heavenly_stems);
+    assert_eq!(
+        [Some(TenGods::Companion), Some(TenGods::EatingGod), Some(TenGods::IndirectResource)],
+        month.hidden_stems
+    );
+    assert_eq!(None, day.heavenly_stems);
+    assert_eq!(Some(TenGods::SevenKillings), hour.heavenly_stems);
+    assert_eq!(
+        [Some(TenGods::IndirectResource), Some(TenGods::RobWealth), Some(TenGods::DirectOfficer)],
+        hour.hidden_stems
+    );
+}
+
+#[test]
+fn parse_str() {
+    for i in 1..=10 {
+        let ten_gods = unsafe { TenGods::from_ordinal_unsafe(i) };
+
+        assert_eq!(
+            ten_gods,
+            TenGods::parse_str(ten_gods.to_str(ChineseVariant::Traditional)).unwrap()
+        );
+        assert_eq!(ten_gods, TenGods::parse_str(ten_gods.to_str(ChineseVariant::Simple)).unwrap());
+    }
+
+    assert_eq!("七殺", TenGods::SevenKillings.to_string());
+    assert_eq!("偏财", format!("{:#}", TenGods::IndirectWealth));
+    assert!(TenGods::parse_str("偏官").is_none());
+}
@@ -0,0 +1,31 @@
+use chinese_lunisolar_calendar::{ChineseVariant, YinYang};
+
+#[test]
+fn parse_str() {
+    assert_eq!(YinYang::Yang, YinYang::parse_str("陽").unwrap());
+    assert_eq!(YinYang::Yang, YinYang::parse_str("阳").unwrap());
+    assert_eq!(YinYang::Yin, YinYang::parse_str("陰").unwrap());
+    assert_eq!(YinYang::Yin, YinYang::parse_str("阴").unwrap());
+    assert!(YinYang::parse_str("中").is_none());
+}
+
+#[test]
+fn to_str() {
+    assert_eq!("陽", YinYang::Yang.to_str(ChineseVariant::Traditional));
+    assert_eq!("阳", YinYang::Yang.to_str(ChineseVariant::Simple));
+    assert_eq!("陰", YinYang::Yin.to_str(ChineseVariant::Traditional));
+    assert_eq!("阴", YinYang::Yin.to_str(ChineseVariant::Simple));
+}
+
+#[test]
+fn from_char() {
+    assert_eq!(YinYang::Yang, YinYang::from_char('陽').unwrap());
+    assert_eq!(YinYang::Yin, YinYang::from_char('阴').unwrap());
+    assert!(YinYang::from_char('中').is_none());
+}
+
+#[test]
+fn to_char() {
+    assert_eq!('陽', YinYang::Yang.to_char(ChineseVariant::Traditional));
+    assert_eq!('阴', YinYang::Yin.to_char(ChineseVariant::Simple));
+}
@@ -1,4 +1,6 @@
-use chinese_lunisolar_calendar::{ChineseVariant, EarthlyBranch, Zodiac};
+use chinese_lunisolar_calendar::{
+    ChineseVariant, EarthlyBranch, FiveElements, Zodiac, ZodiacCompatibility,
+};
 
 #[test]
 fn parse_str() {
@@ -55,3 +57,38 @@ fn to_earthly_branch() {
     assert_eq!(EarthlyBranch::Fifth, Zodiac::Dragon.to_earthly_branch());
     assert_eq!(EarthlyBranch::Twelfth, Zodiac::Pig.to_earthly_branch());
 }
+
+#[test]
+fn compatibility() {
+    let zodiac = |c| Zodiac::from_char(c).unwrap();
+    let compatibility = |a, b| {
+        let result = zodiac(a).compatibility(zodiac(b));
+
+        assert_eq!(result, zodiac(b).compatibility(zodiac(a)));
+
+        result
+    };
+
+    assert_eq!(ZodiacCompatibility::SixHarmony(FiveElements::Wood), compatibility('虎', '豬'));
+    assert_eq!(ZodiacCompatibility::SixHarmony(FiveElements::Water), compatibility('蛇', '猴'));
+    assert_eq!(ZodiacCompatibility::ThreeHarmony(FiveElements::Metal), compatibility('蛇', '雞'));
+    assert_eq!(ZodiacCompatibility::ThreeHarmony(FiveElements::Wood), compatibility('兔', '羊'));
+    assert_eq!(ZodiacCompatibility::SixClash, compatibility('牛', '羊'));
+    assert_eq!(ZodiacCompatibility::SixClash, compatibility('虎', '猴'));
+    assert_eq!(ZodiacCompatibility::Harm, compatibility('虎', '蛇'));
+    assert_eq!(ZodiacCompatibility::Harm, compatibility('鼠', '羊'));
+    assert_eq!(ZodiacCompatibility::Punishment, compatibility('牛', '狗'));
+    assert_eq!(ZodiacCompatibility::Punishment, compatibility('龍', '龍'));
+    assert_eq!(ZodiacCompatibility::Neutral, compatibility('鼠', '鼠'));
+    assert_eq!(ZodiacCompatibility::Neutral, compatibility('鼠', '虎'));
+
+    assert!(compatibility('鼠', '牛').is_harmonious());
+    assert!(compatibility('鼠', '馬').is_conflicting());
+    assert!(!compatibility('鼠', '虎').is_harmonious());
+    assert!(!compatibility('鼠', '虎').is_conflicting());
+
+    assert_eq!("三合", compatibility('猴', '龍').to_str(ChineseVariant::Traditional));
+    assert_eq!("六冲", compatibility('猴', '虎').to_str(ChineseVariant::Simple));
+    // 巳申既六合又相刑，以六合為準
+    assert_eq!("六合", compatibility('猴', '蛇').to_string());
+}